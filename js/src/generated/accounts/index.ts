@@ -1,9 +1,25 @@
+export * from './AllocationTracker';
 export * from './AllowListProof';
 export * from './CandyGuard';
+export * from './ConfigBuffer';
+export * from './DelegateRecord';
+export * from './FreezeEscrow';
 export * from './MintCounter';
 
+import { AllocationTracker } from './AllocationTracker';
 import { AllowListProof } from './AllowListProof';
+import { FreezeEscrow } from './FreezeEscrow';
 import { MintCounter } from './MintCounter';
 import { CandyGuard } from './CandyGuard';
+import { ConfigBuffer } from './ConfigBuffer';
+import { DelegateRecord } from './DelegateRecord';
 
-export const accountProviders = { AllowListProof, MintCounter, CandyGuard };
+export const accountProviders = {
+  AllocationTracker,
+  AllowListProof,
+  FreezeEscrow,
+  MintCounter,
+  CandyGuard,
+  ConfigBuffer,
+  DelegateRecord,
+};
@@ -709,6 +709,905 @@ export class AddressNotAuthorizedError extends Error {
 createErrorFromCodeLookup.set(0x1791, () => new AddressNotAuthorizedError());
 createErrorFromNameLookup.set('AddressNotAuthorized', () => new AddressNotAuthorizedError());
 
+/**
+ * MissingFreezeInstruction: 'Missing freeze instruction data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingFreezeInstructionError extends Error {
+  readonly code: number = 0x1792;
+  readonly name: string = 'MissingFreezeInstruction';
+  constructor() {
+    super('Missing freeze instruction data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingFreezeInstructionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1792, () => new MissingFreezeInstructionError());
+createErrorFromNameLookup.set(
+  'MissingFreezeInstruction',
+  () => new MissingFreezeInstructionError(),
+);
+
+/**
+ * FreezeGuardNotEnabled: 'Freeze guard must be enabled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FreezeGuardNotEnabledError extends Error {
+  readonly code: number = 0x1793;
+  readonly name: string = 'FreezeGuardNotEnabled';
+  constructor() {
+    super('Freeze guard must be enabled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FreezeGuardNotEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1793, () => new FreezeGuardNotEnabledError());
+createErrorFromNameLookup.set('FreezeGuardNotEnabled', () => new FreezeGuardNotEnabledError());
+
+/**
+ * FreezeNotInitialized: 'Freeze must be initialized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FreezeNotInitializedError extends Error {
+  readonly code: number = 0x1794;
+  readonly name: string = 'FreezeNotInitialized';
+  constructor() {
+    super('Freeze must be initialized');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FreezeNotInitializedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1794, () => new FreezeNotInitializedError());
+createErrorFromNameLookup.set('FreezeNotInitialized', () => new FreezeNotInitializedError());
+
+/**
+ * MissingFreezePeriod: 'Missing freeze period'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingFreezePeriodError extends Error {
+  readonly code: number = 0x1795;
+  readonly name: string = 'MissingFreezePeriod';
+  constructor() {
+    super('Missing freeze period');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingFreezePeriodError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1795, () => new MissingFreezePeriodError());
+createErrorFromNameLookup.set('MissingFreezePeriod', () => new MissingFreezePeriodError());
+
+/**
+ * FreezeEscrowAlreadyExists: 'The freeze escrow account already exists'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class FreezeEscrowAlreadyExistsError extends Error {
+  readonly code: number = 0x1796;
+  readonly name: string = 'FreezeEscrowAlreadyExists';
+  constructor() {
+    super('The freeze escrow account already exists');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, FreezeEscrowAlreadyExistsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1796, () => new FreezeEscrowAlreadyExistsError());
+createErrorFromNameLookup.set(
+  'FreezeEscrowAlreadyExists',
+  () => new FreezeEscrowAlreadyExistsError(),
+);
+
+/**
+ * ExceededMaximumFreezePeriod: 'Maximum freeze period exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededMaximumFreezePeriodError extends Error {
+  readonly code: number = 0x1797;
+  readonly name: string = 'ExceededMaximumFreezePeriod';
+  constructor() {
+    super('Maximum freeze period exceeded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededMaximumFreezePeriodError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1797, () => new ExceededMaximumFreezePeriodError());
+createErrorFromNameLookup.set(
+  'ExceededMaximumFreezePeriod',
+  () => new ExceededMaximumFreezePeriodError(),
+);
+
+/**
+ * ThawNotEnabled: 'Thaw is not enabled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ThawNotEnabledError extends Error {
+  readonly code: number = 0x1798;
+  readonly name: string = 'ThawNotEnabled';
+  constructor() {
+    super('Thaw is not enabled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ThawNotEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1798, () => new ThawNotEnabledError());
+createErrorFromNameLookup.set('ThawNotEnabled', () => new ThawNotEnabledError());
+
+/**
+ * UnlockNotEnabled: 'Unlock is not enabled (not all NFTs are thawed)'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnlockNotEnabledError extends Error {
+  readonly code: number = 0x1799;
+  readonly name: string = 'UnlockNotEnabled';
+  constructor() {
+    super('Unlock is not enabled (not all NFTs are thawed)');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnlockNotEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x1799, () => new UnlockNotEnabledError());
+createErrorFromNameLookup.set('UnlockNotEnabled', () => new UnlockNotEnabledError());
+
+/**
+ * IncompatibleFreezeGuards: 'Freeze sol payment and freeze token payment guards cannot be enabled together'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class IncompatibleFreezeGuardsError extends Error {
+  readonly code: number = 0x179a;
+  readonly name: string = 'IncompatibleFreezeGuards';
+  constructor() {
+    super('Freeze sol payment and freeze token payment guards cannot be enabled together');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, IncompatibleFreezeGuardsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179a, () => new IncompatibleFreezeGuardsError());
+createErrorFromNameLookup.set(
+  'IncompatibleFreezeGuards',
+  () => new IncompatibleFreezeGuardsError(),
+);
+
+/**
+ * ExceededProgramListSize: 'Maximum number of additional programs exceeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededProgramListSizeError extends Error {
+  readonly code: number = 0x179b;
+  readonly name: string = 'ExceededProgramListSize';
+  constructor() {
+    super('Maximum number of additional programs exceeded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededProgramListSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179b, () => new ExceededProgramListSizeError());
+createErrorFromNameLookup.set('ExceededProgramListSize', () => new ExceededProgramListSizeError());
+
+/**
+ * UnauthorizedProgramFound: 'Transaction had an instruction from an unauthorized program'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnauthorizedProgramFoundError extends Error {
+  readonly code: number = 0x179c;
+  readonly name: string = 'UnauthorizedProgramFound';
+  constructor() {
+    super('Transaction had an instruction from an unauthorized program');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnauthorizedProgramFoundError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179c, () => new UnauthorizedProgramFoundError());
+createErrorFromNameLookup.set(
+  'UnauthorizedProgramFound',
+  () => new UnauthorizedProgramFoundError(),
+);
+
+/**
+ * MissingAllocationInstruction: 'Missing allocation instruction data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingAllocationInstructionError extends Error {
+  readonly code: number = 0x179d;
+  readonly name: string = 'MissingAllocationInstruction';
+  constructor() {
+    super('Missing allocation instruction data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingAllocationInstructionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179d, () => new MissingAllocationInstructionError());
+createErrorFromNameLookup.set(
+  'MissingAllocationInstruction',
+  () => new MissingAllocationInstructionError(),
+);
+
+/**
+ * AllocationNotEnabled: 'Allocation guard must be enabled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllocationNotEnabledError extends Error {
+  readonly code: number = 0x179e;
+  readonly name: string = 'AllocationNotEnabled';
+  constructor() {
+    super('Allocation guard must be enabled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllocationNotEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179e, () => new AllocationNotEnabledError());
+createErrorFromNameLookup.set('AllocationNotEnabled', () => new AllocationNotEnabledError());
+
+/**
+ * AllocationTrackerAlreadyInitialized: 'Allocation tracker is already initialized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllocationTrackerAlreadyInitializedError extends Error {
+  readonly code: number = 0x179f;
+  readonly name: string = 'AllocationTrackerAlreadyInitialized';
+  constructor() {
+    super('Allocation tracker is already initialized');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllocationTrackerAlreadyInitializedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x179f, () => new AllocationTrackerAlreadyInitializedError());
+createErrorFromNameLookup.set(
+  'AllocationTrackerAlreadyInitialized',
+  () => new AllocationTrackerAlreadyInitializedError(),
+);
+
+/**
+ * AllocationTrackerNotInitialized: 'Allocation tracker must be initialized'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllocationTrackerNotInitializedError extends Error {
+  readonly code: number = 0x17a0;
+  readonly name: string = 'AllocationTrackerNotInitialized';
+  constructor() {
+    super('Allocation tracker must be initialized');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllocationTrackerNotInitializedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a0, () => new AllocationTrackerNotInitializedError());
+createErrorFromNameLookup.set(
+  'AllocationTrackerNotInitialized',
+  () => new AllocationTrackerNotInitializedError(),
+);
+
+/**
+ * AllocationLimitReached: 'The maximum number of mints of the allocation was reached'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllocationLimitReachedError extends Error {
+  readonly code: number = 0x17a1;
+  readonly name: string = 'AllocationLimitReached';
+  constructor() {
+    super('The maximum number of mints of the allocation was reached');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllocationLimitReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a1, () => new AllocationLimitReachedError());
+createErrorFromNameLookup.set('AllocationLimitReached', () => new AllocationLimitReachedError());
+
+/**
+ * InvalidNumberOfDestinations: 'Invalid number of destinations'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNumberOfDestinationsError extends Error {
+  readonly code: number = 0x17a2;
+  readonly name: string = 'InvalidNumberOfDestinations';
+  constructor() {
+    super('Invalid number of destinations');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNumberOfDestinationsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a2, () => new InvalidNumberOfDestinationsError());
+createErrorFromNameLookup.set(
+  'InvalidNumberOfDestinations',
+  () => new InvalidNumberOfDestinationsError(),
+);
+
+/**
+ * InvalidSplitBasisPoints: 'The basis points of the destinations must add up to 10000'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidSplitBasisPointsError extends Error {
+  readonly code: number = 0x17a3;
+  readonly name: string = 'InvalidSplitBasisPoints';
+  constructor() {
+    super('The basis points of the destinations must add up to 10000');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidSplitBasisPointsError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a3, () => new InvalidSplitBasisPointsError());
+createErrorFromNameLookup.set('InvalidSplitBasisPoints', () => new InvalidSplitBasisPointsError());
+
+/**
+ * InvalidDutchAuction: 'Invalid dutch auction configuration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDutchAuctionError extends Error {
+  readonly code: number = 0x17a4;
+  readonly name: string = 'InvalidDutchAuction';
+  constructor() {
+    super('Invalid dutch auction configuration');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDutchAuctionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a4, () => new InvalidDutchAuctionError());
+createErrorFromNameLookup.set('InvalidDutchAuction', () => new InvalidDutchAuctionError());
+
+/**
+ * InvalidBondingCurve: 'Invalid bonding curve configuration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidBondingCurveError extends Error {
+  readonly code: number = 0x17a5;
+  readonly name: string = 'InvalidBondingCurve';
+  constructor() {
+    super('Invalid bonding curve configuration');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidBondingCurveError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a5, () => new InvalidBondingCurveError());
+createErrorFromNameLookup.set('InvalidBondingCurve', () => new InvalidBondingCurveError());
+
+/**
+ * MissingVoucherSignature: 'Missing voucher signature instruction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingVoucherSignatureError extends Error {
+  readonly code: number = 0x17a6;
+  readonly name: string = 'MissingVoucherSignature';
+  constructor() {
+    super('Missing voucher signature instruction');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingVoucherSignatureError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a6, () => new MissingVoucherSignatureError());
+createErrorFromNameLookup.set('MissingVoucherSignature', () => new MissingVoucherSignatureError());
+
+/**
+ * VoucherExpired: 'Voucher expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherExpiredError extends Error {
+  readonly code: number = 0x17a7;
+  readonly name: string = 'VoucherExpired';
+  constructor() {
+    super('Voucher expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a7, () => new VoucherExpiredError());
+createErrorFromNameLookup.set('VoucherExpired', () => new VoucherExpiredError());
+
+/**
+ * VoucherAlreadyUsed: 'Voucher has already been used'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class VoucherAlreadyUsedError extends Error {
+  readonly code: number = 0x17a8;
+  readonly name: string = 'VoucherAlreadyUsed';
+  constructor() {
+    super('Voucher has already been used');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, VoucherAlreadyUsedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a8, () => new VoucherAlreadyUsedError());
+createErrorFromNameLookup.set('VoucherAlreadyUsed', () => new VoucherAlreadyUsedError());
+
+/**
+ * AllowedListQuotaReached: 'The mint allowance of the address was reached'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowedListQuotaReachedError extends Error {
+  readonly code: number = 0x17a9;
+  readonly name: string = 'AllowedListQuotaReached';
+  constructor() {
+    super('The mint allowance of the address was reached');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowedListQuotaReachedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17a9, () => new AllowedListQuotaReachedError());
+createErrorFromNameLookup.set('AllowedListQuotaReached', () => new AllowedListQuotaReachedError());
+
+/**
+ * MissingAllowListInstruction: 'Missing allow list instruction data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MissingAllowListInstructionError extends Error {
+  readonly code: number = 0x17aa;
+  readonly name: string = 'MissingAllowListInstruction';
+  constructor() {
+    super('Missing allow list instruction data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MissingAllowListInstructionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17aa, () => new MissingAllowListInstructionError());
+createErrorFromNameLookup.set(
+  'MissingAllowListInstruction',
+  () => new MissingAllowListInstructionError(),
+);
+
+/**
+ * AllowedListProofExpired: 'Allow list proof expired'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowedListProofExpiredError extends Error {
+  readonly code: number = 0x17ab;
+  readonly name: string = 'AllowedListProofExpired';
+  constructor() {
+    super('Allow list proof expired');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowedListProofExpiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ab, () => new AllowedListProofExpiredError());
+createErrorFromNameLookup.set('AllowedListProofExpired', () => new AllowedListProofExpiredError());
+
+/**
+ * AllowedListProofRevoked: 'Allow list proof has been revoked'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class AllowedListProofRevokedError extends Error {
+  readonly code: number = 0x17ac;
+  readonly name: string = 'AllowedListProofRevoked';
+  constructor() {
+    super('Allow list proof has been revoked');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, AllowedListProofRevokedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ac, () => new AllowedListProofRevokedError());
+createErrorFromNameLookup.set('AllowedListProofRevoked', () => new AllowedListProofRevokedError());
+
+/**
+ * InvalidNumberOfBranches: 'Invalid number of combinator branches'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidNumberOfBranchesError extends Error {
+  readonly code: number = 0x17ad;
+  readonly name: string = 'InvalidNumberOfBranches';
+  constructor() {
+    super('Invalid number of combinator branches');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidNumberOfBranchesError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ad, () => new InvalidNumberOfBranchesError());
+createErrorFromNameLookup.set('InvalidNumberOfBranches', () => new InvalidNumberOfBranchesError());
+
+/**
+ * InvalidCombinator: 'Invalid combinator configuration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidCombinatorError extends Error {
+  readonly code: number = 0x17ae;
+  readonly name: string = 'InvalidCombinator';
+  constructor() {
+    super('Invalid combinator configuration');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidCombinatorError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ae, () => new InvalidCombinatorError());
+createErrorFromNameLookup.set('InvalidCombinator', () => new InvalidCombinatorError());
+
+/**
+ * NegatedGuardSucceeded: 'A negated guard of the combinator succeeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NegatedGuardSucceededError extends Error {
+  readonly code: number = 0x17af;
+  readonly name: string = 'NegatedGuardSucceeded';
+  constructor() {
+    super('A negated guard of the combinator succeeded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NegatedGuardSucceededError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17af, () => new NegatedGuardSucceededError());
+createErrorFromNameLookup.set('NegatedGuardSucceeded', () => new NegatedGuardSucceededError());
+
+/**
+ * CombinatorConditionNotMet: 'None of the combinator branches succeeded'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class CombinatorConditionNotMetError extends Error {
+  readonly code: number = 0x17b0;
+  readonly name: string = 'CombinatorConditionNotMet';
+  constructor() {
+    super('None of the combinator branches succeeded');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, CombinatorConditionNotMetError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b0, () => new CombinatorConditionNotMetError());
+createErrorFromNameLookup.set(
+  'CombinatorConditionNotMet',
+  () => new CombinatorConditionNotMetError(),
+);
+
+/**
+ * NoEligibleGroup: 'None of the groups is eligible for the mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class NoEligibleGroupError extends Error {
+  readonly code: number = 0x17b1;
+  readonly name: string = 'NoEligibleGroup';
+  constructor() {
+    super('None of the groups is eligible for the mint');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, NoEligibleGroupError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b1, () => new NoEligibleGroupError());
+createErrorFromNameLookup.set('NoEligibleGroup', () => new NoEligibleGroupError());
+
+/**
+ * UnsupportedLayoutVersion: 'Unsupported layout version of the candy guard data'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class UnsupportedLayoutVersionError extends Error {
+  readonly code: number = 0x17b2;
+  readonly name: string = 'UnsupportedLayoutVersion';
+  constructor() {
+    super('Unsupported layout version of the candy guard data');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, UnsupportedLayoutVersionError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b2, () => new UnsupportedLayoutVersionError());
+createErrorFromNameLookup.set(
+  'UnsupportedLayoutVersion',
+  () => new UnsupportedLayoutVersionError(),
+);
+
+/**
+ * MigrationRequired: 'Candy guard data must be migrated to the current layout version'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MigrationRequiredError extends Error {
+  readonly code: number = 0x17b3;
+  readonly name: string = 'MigrationRequired';
+  constructor() {
+    super('Candy guard data must be migrated to the current layout version');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MigrationRequiredError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b3, () => new MigrationRequiredError());
+createErrorFromNameLookup.set('MigrationRequired', () => new MigrationRequiredError());
+
+/**
+ * InvalidPendingAuthority: 'Signer is not the pending authority of the candy guard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidPendingAuthorityError extends Error {
+  readonly code: number = 0x17b4;
+  readonly name: string = 'InvalidPendingAuthority';
+  constructor() {
+    super('Signer is not the pending authority of the candy guard');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidPendingAuthorityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b4, () => new InvalidPendingAuthorityError());
+createErrorFromNameLookup.set('InvalidPendingAuthority', () => new InvalidPendingAuthorityError());
+
+/**
+ * InvalidDelegate: 'Signer is not the authority or a delegate of the candy guard'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidDelegateError extends Error {
+  readonly code: number = 0x17b5;
+  readonly name: string = 'InvalidDelegate';
+  constructor() {
+    super('Signer is not the authority or a delegate of the candy guard');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidDelegateError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b5, () => new InvalidDelegateError());
+createErrorFromNameLookup.set('InvalidDelegate', () => new InvalidDelegateError());
+
+/**
+ * DelegateRoleNotAllowed: 'Delegate role does not allow the operation'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DelegateRoleNotAllowedError extends Error {
+  readonly code: number = 0x17b6;
+  readonly name: string = 'DelegateRoleNotAllowed';
+  constructor() {
+    super('Delegate role does not allow the operation');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DelegateRoleNotAllowedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b6, () => new DelegateRoleNotAllowedError());
+createErrorFromNameLookup.set('DelegateRoleNotAllowed', () => new DelegateRoleNotAllowedError());
+
+/**
+ * MintLimitNotEnabled: 'Mint limit guard must be enabled'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class MintLimitNotEnabledError extends Error {
+  readonly code: number = 0x17b7;
+  readonly name: string = 'MintLimitNotEnabled';
+  constructor() {
+    super('Mint limit guard must be enabled');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, MintLimitNotEnabledError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b7, () => new MintLimitNotEnabledError());
+createErrorFromNameLookup.set('MintLimitNotEnabled', () => new MintLimitNotEnabledError());
+
+/**
+ * DuplicateGroupLabel: 'Group label already in use'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class DuplicateGroupLabelError extends Error {
+  readonly code: number = 0x17b8;
+  readonly name: string = 'DuplicateGroupLabel';
+  constructor() {
+    super('Group label already in use');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, DuplicateGroupLabelError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b8, () => new DuplicateGroupLabelError());
+createErrorFromNameLookup.set('DuplicateGroupLabel', () => new DuplicateGroupLabelError());
+
+/**
+ * ExceededProgramGuardConfigSize: 'Exceeded the maximum size of the program guard configuration'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ExceededProgramGuardConfigSizeError extends Error {
+  readonly code: number = 0x17b9;
+  readonly name: string = 'ExceededProgramGuardConfigSize';
+  constructor() {
+    super('Exceeded the maximum size of the program guard configuration');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ExceededProgramGuardConfigSizeError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17b9, () => new ExceededProgramGuardConfigSizeError());
+createErrorFromNameLookup.set(
+  'ExceededProgramGuardConfigSize',
+  () => new ExceededProgramGuardConfigSizeError(),
+);
+
+/**
+ * ProgramGuardRejected: 'External program rejected the mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class ProgramGuardRejectedError extends Error {
+  readonly code: number = 0x17ba;
+  readonly name: string = 'ProgramGuardRejected';
+  constructor() {
+    super('External program rejected the mint');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, ProgramGuardRejectedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17ba, () => new ProgramGuardRejectedError());
+createErrorFromNameLookup.set('ProgramGuardRejected', () => new ProgramGuardRejectedError());
+
+/**
+ * InvalidQuantity: 'Invalid number of items to mint'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class InvalidQuantityError extends Error {
+  readonly code: number = 0x17bb;
+  readonly name: string = 'InvalidQuantity';
+  constructor() {
+    super('Invalid number of items to mint');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, InvalidQuantityError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bb, () => new InvalidQuantityError());
+createErrorFromNameLookup.set('InvalidQuantity', () => new InvalidQuantityError());
+
+/**
+ * QuantityNotSupported: 'A guard does not support minting multiple items in the same transaction'
+ *
+ * @category Errors
+ * @category generated
+ */
+export class QuantityNotSupportedError extends Error {
+  readonly code: number = 0x17bc;
+  readonly name: string = 'QuantityNotSupported';
+  constructor() {
+    super('A guard does not support minting multiple items in the same transaction');
+    if (typeof Error.captureStackTrace === 'function') {
+      Error.captureStackTrace(this, QuantityNotSupportedError);
+    }
+  }
+}
+
+createErrorFromCodeLookup.set(0x17bc, () => new QuantityNotSupportedError());
+createErrorFromNameLookup.set('QuantityNotSupported', () => new QuantityNotSupportedError());
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -1,7 +1,24 @@
+export * from './acceptAuthority';
+export * from './addGroup';
+export * from './applyBuffer';
+export * from './clearGuard';
+export * from './closeBuffer';
+export * from './createDelegate';
+export * from './evaluate';
 export * from './initialize';
+export * from './initializeBuffer';
+export * from './migrate';
 export * from './mint';
+export * from './mintMultiple';
+export * from './quote';
+export * from './removeGroup';
+export * from './renameGroup';
+export * from './revokeDelegate';
 export * from './route';
+export * from './setAuthority';
+export * from './setGuards';
 export * from './unwrap';
 export * from './update';
 export * from './withdraw';
 export * from './wrap';
+export * from './writeBuffer';
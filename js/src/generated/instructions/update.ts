@@ -38,7 +38,7 @@ export const updateStruct = new beet.FixableBeetArgsStruct<
  *
  * @property [_writable_] candyGuard
  * @property [**signer**] authority
- * @property [**signer**] payer
+ * @property [_writable_, **signer**] payer
  * @category Instructions
  * @category Update
  * @category generated
@@ -84,7 +84,7 @@ export function createUpdateInstruction(
     },
     {
       pubkey: accounts.payer,
-      isWritable: false,
+      isWritable: true,
       isSigner: true,
     },
     {
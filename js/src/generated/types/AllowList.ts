@@ -8,13 +8,17 @@
 import * as beet from '@metaplex-foundation/beet';
 export type AllowList = {
   merkleRoot: number[] /* size: 32 */;
+  proofTtl: beet.COption<beet.bignum>;
 };
 
 /**
  * @category userTypes
  * @category generated
  */
-export const allowListBeet = new beet.BeetArgsStruct<AllowList>(
-  [['merkleRoot', beet.uniformFixedSizeArray(beet.u8, 32)]],
+export const allowListBeet = new beet.FixableBeetArgsStruct<AllowList>(
+  [
+    ['merkleRoot', beet.uniformFixedSizeArray(beet.u8, 32)],
+    ['proofTtl', beet.coption(beet.i64)],
+  ],
   'AllowList',
 );
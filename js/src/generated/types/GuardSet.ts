@@ -22,6 +22,16 @@ import { AddressGate, addressGateBeet } from './AddressGate';
 import { NftGate, nftGateBeet } from './NftGate';
 import { NftBurn, nftBurnBeet } from './NftBurn';
 import { TokenBurn, tokenBurnBeet } from './TokenBurn';
+import { FreezeSolPayment, freezeSolPaymentBeet } from './FreezeSolPayment';
+import { FreezeTokenPayment, freezeTokenPaymentBeet } from './FreezeTokenPayment';
+import { ProgramGate, programGateBeet } from './ProgramGate';
+import { Allocation, allocationBeet } from './Allocation';
+import { SolPaymentSplit, solPaymentSplitBeet } from './SolPaymentSplit';
+import { DutchAuction, dutchAuctionBeet } from './DutchAuction';
+import { BondingCurve, bondingCurveBeet } from './BondingCurve';
+import { SignedVoucher, signedVoucherBeet } from './SignedVoucher';
+import { Combinator, combinatorBeet } from './Combinator';
+import { ProgramGuard, programGuardBeet } from './ProgramGuard';
 export type GuardSet = {
   botTax: beet.COption<BotTax>;
   solPayment: beet.COption<SolPayment>;
@@ -39,6 +49,16 @@ export type GuardSet = {
   nftGate: beet.COption<NftGate>;
   nftBurn: beet.COption<NftBurn>;
   tokenBurn: beet.COption<TokenBurn>;
+  freezeSolPayment: beet.COption<FreezeSolPayment>;
+  freezeTokenPayment: beet.COption<FreezeTokenPayment>;
+  programGate: beet.COption<ProgramGate>;
+  allocation: beet.COption<Allocation>;
+  solPaymentSplit: beet.COption<SolPaymentSplit>;
+  dutchAuction: beet.COption<DutchAuction>;
+  bondingCurve: beet.COption<BondingCurve>;
+  signedVoucher: beet.COption<SignedVoucher>;
+  combinator: beet.COption<Combinator>;
+  programGuard: beet.COption<ProgramGuard>;
 };
 
 /**
@@ -63,6 +83,16 @@ export const guardSetBeet = new beet.FixableBeetArgsStruct<GuardSet>(
     ['nftGate', beet.coption(nftGateBeet)],
     ['nftBurn', beet.coption(nftBurnBeet)],
     ['tokenBurn', beet.coption(tokenBurnBeet)],
+    ['freezeSolPayment', beet.coption(freezeSolPaymentBeet)],
+    ['freezeTokenPayment', beet.coption(freezeTokenPaymentBeet)],
+    ['programGate', beet.coption(programGateBeet)],
+    ['allocation', beet.coption(allocationBeet)],
+    ['solPaymentSplit', beet.coption(solPaymentSplitBeet)],
+    ['dutchAuction', beet.coption(dutchAuctionBeet)],
+    ['bondingCurve', beet.coption(bondingCurveBeet)],
+    ['signedVoucher', beet.coption(signedVoucherBeet)],
+    ['combinator', beet.coption(combinatorBeet)],
+    ['programGuard', beet.coption(programGuardBeet)],
   ],
   'GuardSet',
 );
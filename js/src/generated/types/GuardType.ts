@@ -27,6 +27,16 @@ export enum GuardType {
   NftGate,
   NftBurn,
   TokenBurn,
+  FreezeSolPayment,
+  FreezeTokenPayment,
+  ProgramGate,
+  Allocation,
+  SolPaymentSplit,
+  DutchAuction,
+  BondingCurve,
+  SignedVoucher,
+  Combinator,
+  ProgramGuard,
 }
 
 /**
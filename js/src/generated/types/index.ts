@@ -1,21 +1,44 @@
 export * from './AddressGate';
+export * from './Allocation';
+export * from './AllocationInstruction';
 export * from './AllowList';
+export * from './AllowListInstruction';
+export * from './AllowListQuota';
+export * from './BondingCurve';
 export * from './BotTax';
 export * from './CandyGuardData';
+export * from './Combinator';
+export * from './DelegateRole';
+export * from './DutchAuction';
 export * from './EndDate';
+export * from './EvaluationResult';
+export * from './FreezeInstruction';
+export * from './FreezeSolPayment';
+export * from './FreezeTokenPayment';
 export * from './Gatekeeper';
 export * from './Group';
+export * from './GuardCharge';
+export * from './GuardError';
 export * from './GuardSet';
 export * from './GuardType';
 export * from './MintLimit';
 export * from './NftBurn';
 export * from './NftGate';
 export * from './NftPayment';
+export * from './PriceQuote';
+export * from './ProgramGate';
+export * from './ProgramGuard';
+export * from './ProgramGuardArgs';
+export * from './ProgramGuardHook';
 export * from './RedeemedAmount';
 export * from './RouteArgs';
+export * from './SignedVoucher';
 export * from './SolPayment';
+export * from './SolPaymentSplit';
+export * from './SplitDestination';
 export * from './StartDate';
 export * from './ThirdPartySigner';
+export * from './TokenAmount';
 export * from './TokenBurn';
 export * from './TokenGate';
 export * from './TokenPayment';
@@ -1,5 +1,7 @@
 import { BN } from 'bn.js';
 import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { PublicKey } from '@solana/web3.js';
 import { logDebug } from './utils/log';
 import {
   allowListBeet,
@@ -22,6 +24,16 @@ import { addressGateBeet } from './generated/types/AddressGate';
 import { nftGateBeet } from './generated/types/NftGate';
 import { nftBurnBeet } from './generated/types/NftBurn';
 import { tokenBurnBeet } from './generated/types/TokenBurn';
+import { freezeSolPaymentBeet } from './generated/types/FreezeSolPayment';
+import { freezeTokenPaymentBeet } from './generated/types/FreezeTokenPayment';
+import { programGateBeet } from './generated/types/ProgramGate';
+import { allocationBeet } from './generated/types/Allocation';
+import { solPaymentSplitBeet } from './generated/types/SolPaymentSplit';
+import { dutchAuctionBeet } from './generated/types/DutchAuction';
+import { bondingCurveBeet } from './generated/types/BondingCurve';
+import { signedVoucherBeet } from './generated/types/SignedVoucher';
+import { combinatorBeet } from './generated/types/Combinator';
+import { programGuardBeet } from './generated/types/ProgramGuard';
 
 /**
  * Matching the guards of the related struct in the Rust program.
@@ -60,6 +72,26 @@ import { tokenBurnBeet } from './generated/types/TokenBurn';
  *   pub nft_burn: Option<NftBurn>,
  *   /// Token burn guard (burn a specified amount of spl-token)
  *   pub token_burn: Option<NftBurn>,
+ *   /// Freeze sol payment guard (set the price for the mint in lamports with a freeze period).
+ *   pub freeze_sol_payment: Option<FreezeSolPayment>,
+ *   /// Freeze token payment guard (set the price for the mint in spl-token amount with a freeze period).
+ *   pub freeze_token_payment: Option<FreezeTokenPayment>,
+ *   /// Program gate guard (restricts the programs that can be in a mint transaction).
+ *   pub program_gate: Option<ProgramGate>,
+ *   /// Allocation guard (specify the maximum number of mints in a group).
+ *   pub allocation: Option<Allocation>,
+ *   /// Sol payment split guard (split the payment in SOL between multiple destinations).
+ *   pub sol_payment_split: Option<SolPaymentSplit>,
+ *   /// Dutch auction guard (set the price of the mint in SOL, decreasing over time).
+ *   pub dutch_auction: Option<DutchAuction>,
+ *   /// Bonding curve guard (set the price of the mint based on the number of items redeemed).
+ *   pub bonding_curve: Option<BondingCurve>,
+ *   /// Signed voucher guard (requires a voucher signed by an off-chain authority).
+ *   pub signed_voucher: Option<SignedVoucher>,
+ *   /// Combinator guard (combine other guards using any-of, all-of and not logic).
+ *   pub combinator: Option<Combinator>,
+ *   /// Program guard (delegates the validation and actions of the mint to an external program).
+ *   pub program_guard: Option<ProgramGuard>,
  * }
  * ```
  */
@@ -81,6 +113,16 @@ type Guards = {
   /* 14 */ nftGateEnabled: boolean;
   /* 15 */ nftBurnEnabled: boolean;
   /* 16 */ tokenBurnEnabled: boolean;
+  /* 17 */ freezeSolPaymentEnabled: boolean;
+  /* 18 */ freezeTokenPaymentEnabled: boolean;
+  /* 19 */ programGateEnabled: boolean;
+  /* 20 */ allocationEnabled: boolean;
+  /* 21 */ solPaymentSplitEnabled: boolean;
+  /* 22 */ dutchAuctionEnabled: boolean;
+  /* 23 */ bondingCurveEnabled: boolean;
+  /* 24 */ signedVoucherEnabled: boolean;
+  /* 25 */ combinatorEnabled: boolean;
+  /* 26 */ programGuardEnabled: boolean;
 };
 
 const GUARDS_SIZE = {
@@ -92,7 +134,7 @@ const GUARDS_SIZE = {
   /* 06 */ tokenGate: 40,
   /* 07 */ gatekeeper: 33,
   /* 08 */ endDate: 8,
-  /* 09 */ allowList: 32,
+  /* 09 */ allowList: 41,
   /* 10 */ mintLimit: 3,
   /* 11 */ nftPayment: 64,
   /* 12 */ redeemedAmount: 8,
@@ -100,16 +142,54 @@ const GUARDS_SIZE = {
   /* 14 */ nftGate: 32,
   /* 15 */ nftBurn: 32,
   /* 16 */ tokenBurn: 40,
+  /* 17 */ freezeSolPayment: 40,
+  /* 18 */ freezeTokenPayment: 72,
+  /* 19 */ programGate: 164,
+  /* 20 */ allocation: 5,
+  /* 21 */ solPaymentSplit: 182,
+  /* 22 */ dutchAuction: 72,
+  /* 23 */ bondingCurve: 89,
+  /* 24 */ signedVoucher: 32,
+  /* 25 */ combinator: 44,
+  /* 26 */ programGuard: 101,
 };
-const GUARDS_COUNT = 16;
-const MAX_LABEL_LENGTH = 6;
+const GUARDS_COUNT = 26;
+// group label size of layout versions before labels were length-prefixed (version 3)
+const LEGACY_LABEL_LENGTH = 6;
+// number of guard flags in each word of the feature bitmap – the last bit
+// of a word indicates that another word follows
+const FEATURES_PER_WORD = 63;
+const MAX_FEATURE_WORDS = 4;
+// flag of the layout header indicating that the guards data is versioned – accounts
+// created before the layout was versioned start with the feature flags of the
+// default guard set, which never have this bit set
+const LAYOUT_HEADER_FLAG = 63;
+export const LAYOUT_VERSION = 3;
+// bytes used by the header flag and layout version
+const VERSION_SIZE = 8;
+// bytes used by the layout header: header flag and layout version + pending authority
+const HEADER_SIZE = VERSION_SIZE + 32;
+// size of the allow list guard before the layout was versioned (merkle root only)
+const LEGACY_ALLOW_LIST_SIZE = 32;
 
-function determineGuards(buffer: Buffer): Guards {
-  const enabled = new BN(beet.u64.read(buffer, 0)).toNumber();
+function determineGuards(buffer: Buffer): { guards: Guards; offset: number } {
+  const words: BN[] = [];
+  let offset = 0;
+  let word: BN;
+
+  do {
+    if (words.length === MAX_FEATURE_WORDS) {
+      throw new Error('Invalid feature bitmap: too many words');
+    }
+    word = new BN(beet.u64.read(buffer, offset));
+    words.push(word);
+    offset += beet.u64.byteSize;
+  } while (word.testn(FEATURES_PER_WORD));
 
   const guards: boolean[] = [];
   for (let i = 0; i < GUARDS_COUNT; i++) {
-    guards.push(!!((1 << i) & enabled));
+    const index = Math.floor(i / FEATURES_PER_WORD);
+    guards.push(index < words.length && words[index].testn(i % FEATURES_PER_WORD));
   }
 
   const [
@@ -129,31 +209,105 @@ function determineGuards(buffer: Buffer): Guards {
     nftGateEnabled,
     nftBurnEnabled,
     tokenBurnEnabled,
+    freezeSolPaymentEnabled,
+    freezeTokenPaymentEnabled,
+    programGateEnabled,
+    allocationEnabled,
+    solPaymentSplitEnabled,
+    dutchAuctionEnabled,
+    bondingCurveEnabled,
+    signedVoucherEnabled,
+    combinatorEnabled,
+    programGuardEnabled,
   ] = guards;
 
   return {
-    botTaxEnabled,
-    solPaymentEnabled,
-    tokenPaymentEnabled,
-    startDateEnabled,
-    thirdPartySignerEnabled,
-    tokenGateEnabled,
-    gatekeeperEnabled,
-    endDateEnabled,
-    allowListEnabled,
-    mintLimitEnabled,
-    nftPaymentEnabled,
-    redeemedAmountEnabled,
-    addressGateEnabled,
-    nftGateEnabled,
-    nftBurnEnabled,
-    tokenBurnEnabled,
+    guards: {
+      botTaxEnabled,
+      solPaymentEnabled,
+      tokenPaymentEnabled,
+      startDateEnabled,
+      thirdPartySignerEnabled,
+      tokenGateEnabled,
+      gatekeeperEnabled,
+      endDateEnabled,
+      allowListEnabled,
+      mintLimitEnabled,
+      nftPaymentEnabled,
+      redeemedAmountEnabled,
+      addressGateEnabled,
+      nftGateEnabled,
+      nftBurnEnabled,
+      tokenBurnEnabled,
+      freezeSolPaymentEnabled,
+      freezeTokenPaymentEnabled,
+      programGateEnabled,
+      allocationEnabled,
+      solPaymentSplitEnabled,
+      dutchAuctionEnabled,
+      bondingCurveEnabled,
+      signedVoucherEnabled,
+      combinatorEnabled,
+      programGuardEnabled,
+    },
+    offset,
   };
 }
 
+/**
+ * Returns the layout version of the guards data. Accounts created before the
+ * layout was versioned do not have a header and are reported as version `0`.
+ */
+export function parseLayoutVersion(buffer: Buffer): number {
+  const header = new BN(beet.u64.read(buffer, 0));
+  return header.testn(LAYOUT_HEADER_FLAG) ? header.maskn(8).toNumber() : 0;
+}
+
+/**
+ * Returns the authority staged to replace the current authority of the candy guard.
+ * Only layout versions from `2` onwards store a pending authority.
+ */
+export function parsePendingAuthority(buffer: Buffer): PublicKey | null {
+  if (parseLayoutVersion(buffer) < 2) {
+    return null;
+  }
+
+  const pendingAuthority = beetSolana.publicKey.read(buffer, VERSION_SIZE);
+  return pendingAuthority.equals(PublicKey.default) ? null : pendingAuthority;
+}
+
+/**
+ * Returns the group label at the start of the buffer and the number of bytes it uses.
+ * Layout versions before `3` store labels using a fixed number of bytes padded with
+ * zeros, while the current version uses a (u8) length prefix.
+ */
+function parseLabel(buffer: Buffer, version: number): { label: string; size: number } {
+  if (version < 3) {
+    const label = buffer.subarray(0, LEGACY_LABEL_LENGTH).toString().replace(/\0+$/, '');
+    return { label, size: LEGACY_LABEL_LENGTH };
+  }
+
+  const length = beet.u8.read(buffer, 0);
+  const label = buffer.subarray(beet.u8.byteSize, beet.u8.byteSize + length).toString();
+  return { label, size: beet.u8.byteSize + length };
+}
+
 export function parseData(buffer: Buffer): CandyGuardData {
+  const version = parseLayoutVersion(buffer);
+
+  if (version > LAYOUT_VERSION) {
+    throw new Error(`Unsupported layout version ${version}`);
+  }
+  // skips the layout header (the header of version 1 does not include the
+  // pending authority)
+  if (version > 1) {
+    buffer = buffer.subarray(HEADER_SIZE);
+  } else if (version > 0) {
+    buffer = buffer.subarray(VERSION_SIZE);
+  }
+
   // parses the default guard set
-  const { guardSet: defaultSet, offset } = parseGuardSet(buffer);
+  const { guardSet: defaultSet, offset } = parseGuardSet(buffer, version);
   // retrieves the number of groups
   const groupsCount = new BN(beet.u32.read(buffer, offset)).toNumber();
   const groups: Group[] = [];
@@ -161,9 +315,9 @@ export function parseData(buffer: Buffer): CandyGuardData {
   let cursor = beet.u32.byteSize + offset;
   for (let i = 0; i < groupsCount; i++) {
     // parses each individual group
-    const label = buffer.subarray(cursor, cursor + MAX_LABEL_LENGTH).toString();
-    cursor += MAX_LABEL_LENGTH;
-    const { guardSet: guards, offset } = parseGuardSet(buffer.subarray(cursor));
+    const { label, size } = parseLabel(buffer.subarray(cursor), version);
+    cursor += size;
+    const { guardSet: guards, offset } = parseGuardSet(buffer.subarray(cursor), version);
     groups.push({ label, guards });
     cursor += offset;
   }
@@ -174,8 +328,8 @@ export function parseData(buffer: Buffer): CandyGuardData {
   };
 }
 
-function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
-  const guards = determineGuards(buffer);
+function parseGuardSet(buffer: Buffer, version: number): { guardSet: GuardSet; offset: number } {
+  const { guards, offset } = determineGuards(buffer);
   const {
     botTaxEnabled,
     startDateEnabled,
@@ -193,11 +347,21 @@ function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
     nftGateEnabled,
     nftBurnEnabled,
     tokenBurnEnabled,
+    freezeSolPaymentEnabled,
+    freezeTokenPaymentEnabled,
+    programGateEnabled,
+    allocationEnabled,
+    solPaymentSplitEnabled,
+    dutchAuctionEnabled,
+    bondingCurveEnabled,
+    signedVoucherEnabled,
+    combinatorEnabled,
+    programGuardEnabled,
   } = guards;
   logDebug('Guards: %O', guards);
 
-  // data offset for deserialization (skip u64 features flag)
-  let cursor = beet.u64.byteSize;
+  // data offset for deserialization (skip the words of the feature bitmap)
+  let cursor = offset;
   // deserialized guards
   // eslint-disable-next-line  @typescript-eslint/no-explicit-any
   const data: Record<string, any> = {};
@@ -251,9 +415,16 @@ function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
   }
 
   if (allowListEnabled) {
-    const [allowList] = allowListBeet.deserialize(buffer, cursor);
-    data.allowList = allowList;
-    cursor += GUARDS_SIZE.allowList;
+    if (version === 0) {
+      // accounts created before the layout was versioned only store the merkle root
+      const merkleRoot = [...buffer.subarray(cursor, cursor + LEGACY_ALLOW_LIST_SIZE)];
+      data.allowList = { merkleRoot, proofTtl: null };
+      cursor += LEGACY_ALLOW_LIST_SIZE;
+    } else {
+      const [allowList] = allowListBeet.deserialize(buffer, cursor);
+      data.allowList = allowList;
+      cursor += GUARDS_SIZE.allowList;
+    }
   }
 
   if (mintLimitEnabled) {
@@ -298,6 +469,66 @@ function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
     cursor += GUARDS_SIZE.tokenBurn;
   }
 
+  if (freezeSolPaymentEnabled) {
+    const [freezeSolPayment] = freezeSolPaymentBeet.deserialize(buffer, cursor);
+    data.freezeSolPayment = freezeSolPayment;
+    cursor += GUARDS_SIZE.freezeSolPayment;
+  }
+
+  if (freezeTokenPaymentEnabled) {
+    const [freezeTokenPayment] = freezeTokenPaymentBeet.deserialize(buffer, cursor);
+    data.freezeTokenPayment = freezeTokenPayment;
+    cursor += GUARDS_SIZE.freezeTokenPayment;
+  }
+
+  if (programGateEnabled) {
+    const [programGate] = programGateBeet.deserialize(buffer, cursor);
+    data.programGate = programGate;
+    cursor += GUARDS_SIZE.programGate;
+  }
+
+  if (allocationEnabled) {
+    const [allocation] = allocationBeet.deserialize(buffer, cursor);
+    data.allocation = allocation;
+    cursor += GUARDS_SIZE.allocation;
+  }
+
+  if (solPaymentSplitEnabled) {
+    const [solPaymentSplit] = solPaymentSplitBeet.deserialize(buffer, cursor);
+    data.solPaymentSplit = solPaymentSplit;
+    cursor += GUARDS_SIZE.solPaymentSplit;
+  }
+
+  if (dutchAuctionEnabled) {
+    const [dutchAuction] = dutchAuctionBeet.deserialize(buffer, cursor);
+    data.dutchAuction = dutchAuction;
+    cursor += GUARDS_SIZE.dutchAuction;
+  }
+
+  if (bondingCurveEnabled) {
+    const [bondingCurve] = bondingCurveBeet.deserialize(buffer, cursor);
+    data.bondingCurve = bondingCurve;
+    cursor += GUARDS_SIZE.bondingCurve;
+  }
+
+  if (signedVoucherEnabled) {
+    const [signedVoucher] = signedVoucherBeet.deserialize(buffer, cursor);
+    data.signedVoucher = signedVoucher;
+    cursor += GUARDS_SIZE.signedVoucher;
+  }
+
+  if (combinatorEnabled) {
+    const [combinator] = combinatorBeet.deserialize(buffer, cursor);
+    data.combinator = combinator;
+    cursor += GUARDS_SIZE.combinator;
+  }
+
+  if (programGuardEnabled) {
+    const [programGuard] = programGuardBeet.deserialize(buffer, cursor);
+    data.programGuard = programGuard;
+    cursor += GUARDS_SIZE.programGuard;
+  }
+
   return {
     guardSet: {
       botTax: data.botTax ?? null,
@@ -306,7 +537,7 @@ function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
       startDate: data.startDate ?? null,
       thirdPartySigner: data.thirdPartySigner ?? null,
       tokenGate: data.tokenGate ?? null,
-      gatekeeper: data.gateKeeper ?? null,
+      gatekeeper: data.gatekeeper ?? null,
       endDate: data.endDate ?? null,
       allowList: data.allowList ?? null,
       mintLimit: data.mintLimit ?? null,
@@ -316,6 +547,16 @@ function parseGuardSet(buffer: Buffer): { guardSet: GuardSet; offset: number } {
       nftGate: data.nftGate ?? null,
       nftBurn: data.nftBurn ?? null,
       tokenBurn: data.tokenBurn ?? null,
+      freezeSolPayment: data.freezeSolPayment ?? null,
+      freezeTokenPayment: data.freezeTokenPayment ?? null,
+      programGate: data.programGate ?? null,
+      allocation: data.allocation ?? null,
+      solPaymentSplit: data.solPaymentSplit ?? null,
+      dutchAuction: data.dutchAuction ?? null,
+      bondingCurve: data.bondingCurve ?? null,
+      signedVoucher: data.signedVoucher ?? null,
+      combinator: data.combinator ?? null,
+      programGuard: data.programGuard ?? null,
     },
     offset: cursor,
   };
@@ -0,0 +1,107 @@
+import test from 'tape';
+import spok from 'spok';
+import { newCandyGuardData, InitTransactions, killStuckProcess } from './setup';
+import { DATA_OFFSET, spokSamePubkey } from './utils';
+import { CandyGuard, parsePendingAuthority } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('authority: transfer to a new authority', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: authorityHandler, authorityPair } = await API.authority();
+  const { fstTxHandler: minterHandler, minterPair } = await API.minter();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  // stages the new authority
+
+  const { tx: setAuthorityTx } = await API.setAuthority(
+    t,
+    address,
+    authorityPair.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  await setAuthorityTx.assertSuccess(t);
+
+  let accountInfo = await connection.getAccountInfo(address);
+  spok(
+    t,
+    parsePendingAuthority(accountInfo!.data.subarray(DATA_OFFSET)),
+    spokSamePubkey(authorityPair.publicKey),
+  );
+
+  // only the pending authority can accept
+
+  const { tx: minterAcceptTx } = await API.acceptAuthority(t, address, minterPair, minterHandler);
+  await minterAcceptTx.assertError(t, /not the pending authority/i);
+
+  const { tx: acceptTx } = await API.acceptAuthority(t, address, authorityPair, authorityHandler);
+  await acceptTx.assertSuccess(t);
+
+  const candyGuard = await CandyGuard.fromAccountAddress(connection, address);
+  spok(t, candyGuard, {
+    authority: spokSamePubkey(authorityPair.publicKey),
+  });
+
+  accountInfo = await connection.getAccountInfo(address);
+  t.equal(parsePendingAuthority(accountInfo!.data.subarray(DATA_OFFSET)), null);
+
+  // the previous authority cannot stage a new authority
+
+  const { tx: previousAuthorityTx } = await API.setAuthority(
+    t,
+    address,
+    payerPair.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  await previousAuthorityTx.assertError(t, /has one constraint was violated/i);
+});
+
+test('authority: clear the pending authority', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: authorityHandler, authorityPair } = await API.authority();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  const { tx: setAuthorityTx } = await API.setAuthority(
+    t,
+    address,
+    authorityPair.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  await setAuthorityTx.assertSuccess(t);
+
+  // clears the pending authority
+
+  const { tx: clearAuthorityTx } = await API.setAuthority(
+    t,
+    address,
+    null,
+    payerPair,
+    fstTxHandler,
+  );
+  await clearAuthorityTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  t.equal(parsePendingAuthority(accountInfo!.data.subarray(DATA_OFFSET)), null);
+
+  const { tx: acceptTx } = await API.acceptAuthority(t, address, authorityPair, authorityHandler);
+  await acceptTx.assertError(t, /not the pending authority/i);
+});
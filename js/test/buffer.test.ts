@@ -0,0 +1,144 @@
+import test from 'tape';
+import spok from 'spok';
+import { newCandyGuardData, newGuardSet, InitTransactions, killStuckProcess } from './setup';
+import { candyGuardDataBeet, ConfigBuffer } from '../src/generated';
+import { DATA_OFFSET, spokSameBignum, spokSamePubkey } from './utils';
+import { BN } from 'bn.js';
+import { parseData } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+// maximum number of bytes written on each transaction
+const CHUNK_SIZE = 800;
+
+test('buffer: apply a large configuration', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  // configuration with 20 groups, which does not fit in a single transaction
+
+  const data = newCandyGuardData();
+  data.groups = [];
+
+  for (let i = 0; i < 20; i++) {
+    const guards = newGuardSet();
+    guards.allowList = {
+      merkleRoot: [...Buffer.alloc(32, i)],
+      proofTtl: null,
+    };
+    guards.solPayment = {
+      lamports: new BN(100000000 + i),
+      destination: payerPair.publicKey,
+    };
+    data.groups.push({
+      label: `G${i}`,
+      guards,
+    });
+  }
+
+  const [serialized] = candyGuardDataBeet.serialize(data);
+  t.true(serialized.length > 1232, 'configuration exceeds the transaction size');
+
+  // stages the configuration
+
+  const { tx: initializeBufferTx, buffer } = await API.initializeBuffer(
+    t,
+    address,
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeBufferTx.assertSuccess(t);
+
+  const configBuffer = await ConfigBuffer.fromAccountAddress(connection, buffer);
+  spok(t, configBuffer, {
+    candyGuard: spokSamePubkey(address),
+    authority: spokSamePubkey(payerPair.publicKey),
+  });
+
+  for (let offset = 0; offset < serialized.length; offset += CHUNK_SIZE) {
+    const { tx: writeBufferTx } = await API.writeBuffer(
+      t,
+      address,
+      buffer,
+      offset,
+      serialized.subarray(offset, offset + CHUNK_SIZE),
+      payerPair,
+      fstTxHandler,
+    );
+    await writeBufferTx.assertSuccess(t);
+  }
+
+  // applies the configuration
+
+  const { tx: applyBufferTx } = await API.applyBuffer(t, address, buffer, payerPair, fstTxHandler);
+  await applyBufferTx.assertSuccess(t);
+
+  t.equal(await connection.getAccountInfo(buffer), null, 'buffer closed');
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  t.equal(candyGuardData.groups?.length, 20, 'number of groups');
+  spok(t, candyGuardData.groups?.[19], {
+    label: 'G19',
+    guards: {
+      solPayment: {
+        lamports: spokSameBignum(100000019),
+      },
+    },
+  });
+});
+
+test('buffer: close', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: authorityHandler, authorityPair } = await API.authority();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  const { tx: initializeBufferTx, buffer } = await API.initializeBuffer(
+    t,
+    address,
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeBufferTx.assertSuccess(t);
+
+  // only the buffer or candy guard authority can close the buffer
+
+  const { tx: authorityCloseTx } = await API.closeBuffer(
+    t,
+    address,
+    buffer,
+    payerPair.publicKey,
+    authorityPair,
+    authorityHandler,
+  );
+  await authorityCloseTx.assertError(t, /constraint was violated/i);
+
+  const { tx: closeBufferTx } = await API.closeBuffer(
+    t,
+    address,
+    buffer,
+    payerPair.publicKey,
+    payerPair,
+    fstTxHandler,
+  );
+  await closeBufferTx.assertSuccess(t);
+
+  t.equal(await connection.getAccountInfo(buffer), null, 'buffer closed');
+});
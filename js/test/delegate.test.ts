@@ -0,0 +1,240 @@
+import test from 'tape';
+import spok from 'spok';
+import { newCandyGuardData, newGuardSet, InitTransactions, killStuckProcess } from './setup';
+import { DelegateRecord, GuardType } from '../src/generated';
+import { DATA_OFFSET, spokSameBignum, spokSamePubkey } from './utils';
+import { BN } from 'bn.js';
+import { parseData } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('delegate: update the default guard set', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: delegateHandler, authorityPair: delegatePair } = await API.authority();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1663965742,
+  };
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  // grants the delegate the update of the start date
+
+  const { tx: createDelegateTx, delegateRecord } = await API.createDelegate(
+    t,
+    address,
+    delegatePair.publicKey,
+    { __kind: 'Update', label: null, guards: [GuardType.StartDate] },
+    payerPair,
+    fstTxHandler,
+  );
+  await createDelegateTx.assertSuccess(t);
+
+  const record = await DelegateRecord.fromAccountAddress(connection, delegateRecord);
+  spok(t, record, {
+    candyGuard: spokSamePubkey(address),
+    delegate: spokSamePubkey(delegatePair.publicKey),
+    authority: spokSamePubkey(payerPair.publicKey),
+  });
+
+  // the delegate can update the start date
+
+  const startDateData = newCandyGuardData();
+  startDateData.default.startDate = {
+    date: 1672531200,
+  };
+  startDateData.default.solPayment = data.default.solPayment;
+
+  const { tx: startDateTx } = await API.update(
+    t,
+    address,
+    startDateData,
+    delegatePair,
+    delegateHandler,
+    delegateRecord,
+  );
+  await startDateTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.startDate, {
+    date: spokSameBignum(1672531200),
+  });
+
+  // the delegate cannot update the sol payment
+
+  const solPaymentData = newCandyGuardData();
+  solPaymentData.default.startDate = startDateData.default.startDate;
+  solPaymentData.default.solPayment = {
+    lamports: new BN(100000000),
+    destination: delegatePair.publicKey,
+  };
+
+  const { tx: solPaymentTx } = await API.update(
+    t,
+    address,
+    solPaymentData,
+    delegatePair,
+    delegateHandler,
+    delegateRecord,
+  );
+  await solPaymentTx.assertError(t, /Delegate role does not allow the operation/i);
+
+  // the delegate cannot update without the delegate record
+
+  const { tx: noRecordTx } = await API.update(
+    t,
+    address,
+    startDateData,
+    delegatePair,
+    delegateHandler,
+  );
+  await noRecordTx.assertError(t, /not the authority or a delegate/i);
+});
+
+test('delegate: update a group', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: delegateHandler, authorityPair: delegatePair } = await API.authority();
+
+  const data = newCandyGuardData();
+  data.groups = [];
+
+  const vipGroup = newGuardSet();
+  vipGroup.startDate = {
+    date: 1663965742,
+  };
+  data.groups.push({
+    label: 'VIP',
+    guards: vipGroup,
+  });
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  const { tx: createDelegateTx, delegateRecord } = await API.createDelegate(
+    t,
+    address,
+    delegatePair.publicKey,
+    { __kind: 'Update', label: 'VIP', guards: [GuardType.StartDate, GuardType.EndDate] },
+    payerPair,
+    fstTxHandler,
+  );
+  await createDelegateTx.assertSuccess(t);
+
+  // the delegate can update the dates of the group
+
+  const groupData = newCandyGuardData();
+  const updatedGroup = newGuardSet();
+  updatedGroup.startDate = {
+    date: 1672531200,
+  };
+  updatedGroup.endDate = {
+    date: 1675209600,
+  };
+  groupData.groups = [{ label: 'VIP', guards: updatedGroup }];
+
+  const { tx: groupTx } = await API.update(
+    t,
+    address,
+    groupData,
+    delegatePair,
+    delegateHandler,
+    delegateRecord,
+  );
+  await groupTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.groups?.[0].guards.endDate, {
+    date: spokSameBignum(1675209600),
+  });
+
+  // the delegate cannot update the dates of the default guard set
+
+  const defaultData = newCandyGuardData();
+  defaultData.default.startDate = {
+    date: 1672531200,
+  };
+  defaultData.groups = groupData.groups;
+
+  const { tx: defaultTx } = await API.update(
+    t,
+    address,
+    defaultData,
+    delegatePair,
+    delegateHandler,
+    delegateRecord,
+  );
+  await defaultTx.assertError(t, /Delegate role does not allow the operation/i);
+});
+
+test('delegate: revoke', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const { fstTxHandler: delegateHandler, authorityPair: delegatePair } = await API.authority();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  const { tx: createDelegateTx, delegateRecord } = await API.createDelegate(
+    t,
+    address,
+    delegatePair.publicKey,
+    { __kind: 'Update', label: null, guards: [GuardType.StartDate] },
+    payerPair,
+    fstTxHandler,
+  );
+  await createDelegateTx.assertSuccess(t);
+
+  const { tx: revokeDelegateTx } = await API.revokeDelegate(
+    t,
+    address,
+    delegateRecord,
+    payerPair,
+    fstTxHandler,
+  );
+  await revokeDelegateTx.assertSuccess(t);
+
+  t.equal(await connection.getAccountInfo(delegateRecord), null, 'delegate record closed');
+
+  // the delegate can no longer update
+
+  const updateData = newCandyGuardData();
+  updateData.default.startDate = {
+    date: 1672531200,
+  };
+
+  const { tx: updateTx } = await API.update(
+    t,
+    address,
+    updateData,
+    delegatePair,
+    delegateHandler,
+    delegateRecord,
+  );
+  await updateTx.assertError(t, /not the authority or a delegate/i);
+});
@@ -0,0 +1,102 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { Keypair } from '@solana/web3.js';
+import { BN } from 'bn.js';
+import { GuardType } from '../src/generated';
+import { CandyMachine } from '@metaplex-foundation/mpl-candy-machine-core';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+// feature masks of the guards (bit index of the guard on the guard set)
+const START_DATE = new BN(1).shln(3);
+const END_DATE = new BN(1).shln(7);
+const ADDRESS_GATE = new BN(1).shln(12);
+
+test('evaluate: all guards', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const now = Math.floor(Date.now() / 1000);
+
+  // the start date and address gate fail, the end date succeeds
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: now + 3600,
+  };
+  data.default.endDate = {
+    date: now + 3600,
+  };
+  data.default.addressGate = {
+    address: Keypair.generate().publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // evaluate (as a minter)
+
+  const { minterPair: minter, connection: minterConnection } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const result = await API.evaluate(
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterConnection,
+  );
+
+  t.equal(result.transactionError, null, 'transaction is valid');
+  t.ok(
+    new BN(result.evaluated[0]).eq(START_DATE.or(END_DATE).or(ADDRESS_GATE)),
+    'all guards evaluated',
+  );
+  t.ok(new BN(result.failed[0]).eq(START_DATE.or(ADDRESS_GATE)), 'failed guards');
+  t.deepEqual(
+    result.errors.map((error) => error.guard),
+    [GuardType.StartDate, GuardType.AddressGate],
+    'errors in evaluation order',
+  );
+  t.ok(new BN(result.skipped[0]).isZero(), 'no guards skipped');
+
+  // the evaluation does not mint
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(connection, candyMachine);
+  t.equal(new BN(candyMachineObject.itemsRedeemed).toNumber(), 0, 'no items redeemed');
+});
+
+test('evaluate: no failures', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const { minterPair: minter, connection: minterConnection } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const result = await API.evaluate(
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterConnection,
+  );
+
+  t.ok(new BN(result.evaluated[0]).eq(START_DATE), 'start date evaluated');
+  t.ok(new BN(result.failed[0]).isZero(), 'no guards failed');
+  t.equal(result.errors.length, 0, 'no errors');
+});
@@ -0,0 +1,192 @@
+import test from 'tape';
+import spok from 'spok';
+import * as beet from '@metaplex-foundation/beet';
+import * as beetSolana from '@metaplex-foundation/beet-solana';
+import { sha256 } from '@noble/hashes/sha256';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BN } from 'bn.js';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { spokSameBignum, spokSamePubkey } from './utils';
+import { GuardCharge, guardChargeBeet, GuardType, MintNotLiveError } from '../src/generated';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+type MintEvent = {
+  candyGuard: PublicKey;
+  candyMachine: PublicKey;
+  payer: PublicKey;
+  label: beet.COption<string>;
+  quantity: number;
+  charges: GuardCharge[];
+};
+
+const mintEventBeet = new beet.FixableBeetArgsStruct<MintEvent>(
+  [
+    ['candyGuard', beetSolana.publicKey],
+    ['candyMachine', beetSolana.publicKey],
+    ['payer', beetSolana.publicKey],
+    ['label', beet.coption(beet.utf8String)],
+    ['quantity', beet.u8],
+    ['charges', beet.array(guardChargeBeet)],
+  ],
+  'MintEvent',
+);
+
+type BotTaxEvent = {
+  candyGuard: PublicKey;
+  candyMachine: PublicKey;
+  payer: PublicKey;
+  lamports: beet.bignum;
+  errorCode: beet.bignum;
+};
+
+const botTaxEventBeet = new beet.BeetArgsStruct<BotTaxEvent>(
+  [
+    ['candyGuard', beetSolana.publicKey],
+    ['candyMachine', beetSolana.publicKey],
+    ['payer', beetSolana.publicKey],
+    ['lamports', beet.u64],
+    ['errorCode', beet.u64],
+  ],
+  'BotTaxEvent',
+);
+
+/**
+ * Returns the data of the event with the given name emitted in the logs (without
+ * the event discriminator).
+ */
+function findEvent(logs: string[], name: string): Buffer | undefined {
+  const discriminator = Buffer.from(sha256(`event:${name}`)).subarray(0, 8);
+
+  for (const log of logs) {
+    if (log.startsWith('Program data: ')) {
+      const data = Buffer.from(log.slice('Program data: '.length), 'base64');
+      if (data.subarray(0, 8).equals(discriminator)) {
+        return data.subarray(8);
+      }
+    }
+  }
+
+  return undefined;
+}
+
+test('events: mint', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const destination = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: destination,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  const { txSummary } = await minterMintTx.assertSuccess(t);
+
+  const eventData = findEvent(txSummary.logMessages, 'MintEvent');
+  t.ok(eventData, 'mint event emitted');
+  if (eventData) {
+    const [event] = mintEventBeet.deserialize(eventData);
+    spok(t, event, {
+      candyGuard: spokSamePubkey(candyGuard),
+      candyMachine: spokSamePubkey(candyMachine),
+      payer: spokSamePubkey(minter.publicKey),
+      label: null,
+      quantity: 1,
+    });
+    t.equal(event.charges.length, 1, 'one charge');
+    spok(t, event.charges[0], {
+      guard: GuardType.SolPayment,
+      mint: null,
+      amount: spokSameBignum(100000000),
+    });
+  }
+});
+
+test('events: bot tax', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.botTax = {
+    lamports: 100000000,
+    lastInstruction: false,
+  };
+  data.default.startDate = {
+    date: Math.floor(Date.now() / 1000) + 3600,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  const { txSummary } = await minterMintTx.assertSuccess(t, [/Mint is not live/i, /Botting/i]);
+
+  t.notOk(findEvent(txSummary.logMessages, 'MintEvent'), 'no mint event');
+
+  const eventData = findEvent(txSummary.logMessages, 'BotTaxEvent');
+  t.ok(eventData, 'bot tax event emitted');
+  if (eventData) {
+    const [event] = botTaxEventBeet.deserialize(eventData);
+    spok(t, event, {
+      candyGuard: spokSamePubkey(candyGuard),
+      payer: spokSamePubkey(minter.publicKey),
+      lamports: spokSameBignum(100000000),
+      errorCode: spokSameBignum(new MintNotLiveError().code),
+    });
+  }
+});
@@ -0,0 +1,144 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { u32 } from '@metaplex-foundation/beet';
+import { PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import { AllocationInstruction, GuardType, PROGRAM_ID } from '../../src/generated';
+import {
+  createRouteInstruction,
+  RouteInstructionAccounts,
+  RouteInstructionArgs,
+} from '../../src/generated/instructions/route';
+import { assertIsNotNull } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('allocation', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.allocation = {
+    id: 1,
+    limit: 1,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const [allocationPda] = await PublicKey.findProgramAddress(
+    [Buffer.from('allocation'), Buffer.from([1]), candyGuard.toBuffer(), candyMachine.toBuffer()],
+    PROGRAM_ID,
+  );
+
+  const allocationAccounts = [
+    {
+      pubkey: allocationPda,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  // mint (as a minter) before the allocation tracker is initialized
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    allocationAccounts,
+  );
+  await minterMintTx.assertError(t, /Allocation tracker must be initialized/i);
+
+  // initializes the allocation tracker
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard,
+    candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.Allocation,
+      data: Buffer.from([AllocationInstruction.Initialize]),
+    },
+    label: null,
+  };
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: allocationPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const initializeTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(routeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await initializeTx.assertSuccess(t);
+
+  // mint (as a minter)
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    allocationAccounts,
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  const tracker = await connection.getAccountInfo(allocationPda);
+  assertIsNotNull(t, tracker);
+  t.equal(u32.read(tracker.data, 0), 1, 'allocation tracker count');
+
+  // mint (as a minter) – the allocation limit was reached
+
+  const [, mintForMinter3] = await amman.genLabeledKeypair('Mint Account 3 (minter)');
+  const { tx: minterMintTx3 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter3,
+    minterHandler,
+    minterConnection,
+    allocationAccounts,
+  );
+  await minterMintTx3.assertError(t, /maximum number of mints of the allocation/i);
+});
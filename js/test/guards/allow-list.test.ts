@@ -1,5 +1,5 @@
 import test from 'tape';
-import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData, sleep } from '../setup';
 import { MerkleTree } from 'merkletreejs';
 import { keccak_256 } from '@noble/hashes/sha3';
 import { u32 } from '@metaplex-foundation/beet';
@@ -11,6 +11,7 @@ import {
   RouteInstructionArgs,
 } from '../../src/generated/instructions/route';
 import { GuardType } from '../../src/generated/types/GuardType';
+import { AllowListInstruction } from '../../src/generated/types/AllowListInstruction';
 
 const API = new InitTransactions();
 
@@ -44,6 +45,7 @@ test('allowlist (mint without proof)', async (t) => {
   const data = newCandyGuardData();
   data.default.allowList = {
     merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
   };
 
   const { candyGuard, candyMachine } = await API.deploy(
@@ -130,6 +132,7 @@ test('allowlist (with proof)', async (t) => {
   const data = newCandyGuardData();
   data.default.allowList = {
     merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
   };
 
   const { candyGuard, candyMachine } = await API.deploy(
@@ -246,6 +249,7 @@ test('allowlist (with wrong proof pda)', async (t) => {
   const data = newCandyGuardData();
   data.default.allowList = {
     merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
   };
 
   const { candyGuard, candyMachine } = await API.deploy(
@@ -368,6 +372,7 @@ test('allowlist (large)', async (t) => {
   const data = newCandyGuardData();
   data.default.allowList = {
     merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
   };
 
   const { candyGuard, candyMachine } = await API.deploy(
@@ -459,3 +464,492 @@ test('allowlist (large)', async (t) => {
 
   await minterMintTx.assertSuccess(t);
 });
+
+test('allowlist (with quota)', async (t) => {
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // list of addresses in the allow list – each leaf commits to the mint
+  // allowance of the address
+
+  const leaves: Buffer[] = [];
+
+  for (let i = 0; i < 9; i++) {
+    const [address] = await amman.genLabeledKeypair(`Wallet ${i}`);
+    leaves.push(quotaLeaf(address.toString(), 1));
+  }
+
+  leaves.push(quotaLeaf(minterKeypair.publicKey.toString(), 2));
+
+  // creates the merkle tree
+  const tree = new MerkleTree(leaves.map(keccak_256), keccak_256, { sortPairs: true });
+
+  // deploys a candy guard with the allow list – the allowList guard is configured
+  // with the root of the merkle tree
+
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.allowList = {
+    merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // route instruction
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: minterKeypair.publicKey,
+  };
+
+  const proof = tree.getProof(
+    Buffer.from(keccak_256(quotaLeaf(minterKeypair.publicKey.toString(), 2))),
+  );
+
+  const vectorSizeBuffer = Buffer.alloc(4);
+  u32.write(vectorSizeBuffer, 0, proof.length);
+
+  const allowanceBuffer = Buffer.alloc(4);
+  u32.write(allowanceBuffer, 0, 2);
+
+  const leafBuffers = proof.map((leaf) => leaf.data);
+  // prepares the route arguments with the merkle proof followed by the allowance
+  const routeArgs = Buffer.concat([
+    Buffer.from([AllowListInstruction.Proof]),
+    vectorSizeBuffer,
+    ...leafBuffers,
+    allowanceBuffer,
+  ]);
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.AllowList,
+      data: routeArgs,
+    },
+    label: null,
+  };
+
+  const [proofPda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('allow_list'),
+      tree.getRoot(),
+      minterKeypair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const tx = new Transaction().add(routeIx);
+
+  const h = minterHandler.sendAndConfirmTransaction(tx, [minterKeypair], 'tx: Route');
+
+  await h.assertSuccess(t);
+
+  // mint (as a minter) – the proof PDA must be writable to track the quota
+
+  const proofAccounts = [
+    {
+      pubkey: proofPda,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  for (let i = 0; i < 2; i++) {
+    const [, mintForMinter] = await amman.genLabeledKeypair(`Mint Account ${i} (minter)`);
+    const { tx: minterMintTx } = await API.mint(
+      t,
+      candyGuard,
+      candyMachine,
+      minterKeypair,
+      mintForMinter,
+      minterHandler,
+      minterConnection,
+      proofAccounts,
+    );
+
+    await minterMintTx.assertSuccess(t);
+  }
+
+  // the allowance of the address was consumed
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    proofAccounts,
+  );
+
+  await minterMintTx.assertError(t, /mint allowance of the address was reached/i);
+});
+
+test('allowlist (expired proof)', async (t) => {
+  const addresses: string[] = [];
+
+  // list of addresses in the allow list
+
+  for (let i = 0; i < 9; i++) {
+    const [address] = await amman.genLabeledKeypair(`Wallet ${i}`);
+    addresses.push(address.toString());
+  }
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+  addresses.push(minterKeypair.publicKey.toString());
+
+  // creates the merkle tree
+  const tree = new MerkleTree(addresses.map(keccak_256), keccak_256, { sortPairs: true });
+
+  // deploys a candy guard with the allow list – proofs are valid for 1 second
+
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.allowList = {
+    merkleRoot: [...tree.getRoot()],
+    proofTtl: 1,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // route instruction
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: minterKeypair.publicKey,
+  };
+
+  const proof = tree.getProof(Buffer.from(keccak_256(minterKeypair.publicKey.toString())));
+
+  const vectorSizeBuffer = Buffer.alloc(4);
+  u32.write(vectorSizeBuffer, 0, proof.length);
+
+  const leafBuffers = proof.map((leaf) => leaf.data);
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.AllowList,
+      data: Buffer.concat([
+        Buffer.from([AllowListInstruction.Proof]),
+        vectorSizeBuffer,
+        ...leafBuffers,
+      ]),
+    },
+    label: null,
+  };
+
+  const [proofPda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('allow_list'),
+      tree.getRoot(),
+      minterKeypair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const tx = new Transaction().add(routeIx);
+
+  const h = minterHandler.sendAndConfirmTransaction(tx, [minterKeypair], 'tx: Route');
+
+  await h.assertSuccess(t);
+
+  // waits for the proof to expire
+
+  await sleep(3000);
+
+  // mint (as a minter)
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  await minterMintTx.assertError(t, /Allow list proof expired/i);
+});
+
+test('allowlist (revoke and close proof)', async (t) => {
+  const addresses: string[] = [];
+
+  // list of addresses in the allow list
+
+  for (let i = 0; i < 9; i++) {
+    const [address] = await amman.genLabeledKeypair(`Wallet ${i}`);
+    addresses.push(address.toString());
+  }
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+  addresses.push(minterKeypair.publicKey.toString());
+
+  // creates the merkle tree
+  const tree = new MerkleTree(addresses.map(keccak_256), keccak_256, { sortPairs: true });
+
+  // deploys a candy guard with the allow list – the allowList guard is configured
+  // with the root of the merkle tree
+
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.allowList = {
+    merkleRoot: [...tree.getRoot()],
+    proofTtl: null,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // route instruction
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: minterKeypair.publicKey,
+  };
+
+  const proof = tree.getProof(Buffer.from(keccak_256(minterKeypair.publicKey.toString())));
+
+  const vectorSizeBuffer = Buffer.alloc(4);
+  u32.write(vectorSizeBuffer, 0, proof.length);
+
+  const leafBuffers = proof.map((leaf) => leaf.data);
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.AllowList,
+      data: Buffer.concat([
+        Buffer.from([AllowListInstruction.Proof]),
+        vectorSizeBuffer,
+        ...leafBuffers,
+      ]),
+    },
+    label: null,
+  };
+
+  const [proofPda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('allow_list'),
+      tree.getRoot(),
+      minterKeypair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const tx = new Transaction().add(routeIx);
+
+  const h = minterHandler.sendAndConfirmTransaction(tx, [minterKeypair], 'tx: Route');
+
+  await h.assertSuccess(t);
+
+  // revokes the proof (as the authority)
+
+  const authorityAccounts: RouteInstructionAccounts = {
+    candyGuard: candyGuard,
+    candyMachine: candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const revokeIx = createRouteInstruction(authorityAccounts, {
+    args: {
+      guard: GuardType.AllowList,
+      data: Buffer.concat([
+        Buffer.from([AllowListInstruction.Revoke]),
+        minterKeypair.publicKey.toBuffer(),
+        tree.getRoot(),
+      ]),
+    },
+    label: null,
+  });
+  revokeIx.keys.push(
+    ...[
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const revokeTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(revokeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await revokeTx.assertSuccess(t);
+
+  // mint (as a minter)
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  await minterMintTx.assertError(t, /Allow list proof has been revoked/i);
+
+  // closes the proof PDA (as the authority)
+
+  const closeIx = createRouteInstruction(authorityAccounts, {
+    args: {
+      guard: GuardType.AllowList,
+      data: Buffer.concat([
+        Buffer.from([AllowListInstruction.Close]),
+        minterKeypair.publicKey.toBuffer(),
+        tree.getRoot(),
+      ]),
+    },
+    label: null,
+  });
+  closeIx.keys.push(
+    ...[
+      {
+        pubkey: proofPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: true,
+      },
+    ],
+  );
+
+  const closeTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(closeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await closeTx.assertSuccess(t);
+
+  t.equal(await connection.getAccountInfo(proofPda), null, 'proof PDA closed');
+});
+
+function quotaLeaf(address: string, allowance: number): Buffer {
+  const allowanceBuffer = Buffer.alloc(4);
+  u32.write(allowanceBuffer, 0, allowance);
+
+  return Buffer.concat([Buffer.from(address), allowanceBuffer]);
+}
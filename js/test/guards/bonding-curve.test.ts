@@ -0,0 +1,94 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { Keypair } from '@solana/web3.js';
+import { BN } from 'bn.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('bonding curve (sol)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const destination = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.bondingCurve = {
+    basePrice: new BN(100000000),
+    increment: new BN(50000000),
+    stepSize: new BN(1),
+    tokenMint: null,
+    destination,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const paymentAccounts = [
+    {
+      pubkey: destination,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  // mint (as a minter) – the first item is charged the base price
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    paymentAccounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  t.equal(await connection.getBalance(destination), 100000000, 'first item price');
+
+  // mint (as a minter) – the second item is charged the base price plus one increment
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    paymentAccounts,
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  t.equal(await connection.getBalance(destination), 250000000, 'second item price');
+});
+
+test('bonding curve (invalid configuration)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.bondingCurve = {
+    basePrice: new BN(100000000),
+    increment: new BN(50000000),
+    stepSize: new BN(0),
+    tokenMint: null,
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /Invalid bonding curve configuration/i);
+});
@@ -0,0 +1,191 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { BN } from 'bn.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+// feature masks of the guards (bit index of the guard on the guard set)
+const START_DATE = new BN(1).shln(3);
+const END_DATE = new BN(1).shln(7);
+const ADDRESS_GATE = new BN(1).shln(12);
+
+test('combinator (any of)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const now = Math.floor(Date.now() / 1000);
+
+  // the start date is in the future, but the end date branch succeeds
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: now + 3600,
+  };
+  data.default.endDate = {
+    date: now + 3600,
+  };
+  data.default.combinator = {
+    branches: [START_DATE, END_DATE],
+    negated: 0,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertSuccess(t);
+});
+
+test('combinator (no branch succeeds)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const now = Math.floor(Date.now() / 1000);
+
+  // the start date is in the future and the end date is in the past
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: now + 3600,
+  };
+  data.default.endDate = {
+    date: now - 3600,
+  };
+  data.default.combinator = {
+    branches: [START_DATE, END_DATE],
+    negated: 0,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /None of the combinator branches succeeded/i);
+});
+
+test('combinator (negated)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const now = Math.floor(Date.now() / 1000);
+
+  // the minter address is excluded from the mint
+  const data = newCandyGuardData();
+  data.default.endDate = {
+    date: now + 3600,
+  };
+  data.default.addressGate = {
+    address: minter.publicKey,
+  };
+  data.default.combinator = {
+    branches: [END_DATE],
+    negated: ADDRESS_GATE,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /A negated guard of the combinator succeeded/i);
+
+  // mint (as the authority)
+
+  const [, mintForAuthority] = await amman.genLabeledKeypair('Mint Account (authority)');
+  const { tx: authorityMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    payerPair,
+    mintForAuthority,
+    fstTxHandler,
+    connection,
+  );
+  await authorityMintTx.assertSuccess(t);
+});
+
+test('combinator (invalid configuration)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  // a branch without guards
+  const data = newCandyGuardData();
+  data.default.combinator = {
+    branches: [START_DATE, 0],
+    negated: 0,
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /Invalid combinator configuration/i);
+
+  // a combined guard that is not enabled
+  const missingData = newCandyGuardData();
+  missingData.default.startDate = {
+    date: Math.floor(Date.now() / 1000) + 3600,
+  };
+  missingData.default.combinator = {
+    branches: [START_DATE, END_DATE],
+    negated: 0,
+  };
+
+  const { tx: missingTx } = await API.initialize(t, missingData, payerPair, fstTxHandler);
+  await missingTx.assertError(t, /Invalid combinator configuration/i);
+});
@@ -0,0 +1,82 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { Keypair } from '@solana/web3.js';
+import { BN } from 'bn.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('dutch auction (ended)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const destination = Keypair.generate().publicKey;
+  // the auction has already ended, so the price is the floor price
+  const now = Math.floor(Date.now() / 1000);
+
+  const data = newCandyGuardData();
+  data.default.dutchAuction = {
+    startPrice: new BN(500000000),
+    endPrice: new BN(100000000),
+    startTime: now - 7200,
+    endTime: now - 3600,
+    stepInterval: 600,
+    destination,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: destination,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  t.equal(await connection.getBalance(destination), 100000000, 'destination received the payment');
+});
+
+test('dutch auction (invalid configuration)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const now = Math.floor(Date.now() / 1000);
+
+  const data = newCandyGuardData();
+  // the end price is higher than the start price
+  data.default.dutchAuction = {
+    startPrice: new BN(100000000),
+    endPrice: new BN(500000000),
+    startTime: now,
+    endTime: now + 3600,
+    stepInterval: 600,
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /Invalid dutch auction configuration/i);
+});
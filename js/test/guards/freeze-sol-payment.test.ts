@@ -0,0 +1,334 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { i64 } from '@metaplex-foundation/beet';
+import { findAssociatedTokenAccountPda, findMasterEditionV2Pda } from '@metaplex-foundation/js';
+import { PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import { TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import { BN } from 'bn.js';
+import { FreezeInstruction, GuardType, PROGRAM_ID } from '../../src/generated';
+import {
+  createRouteInstruction,
+  RouteInstructionAccounts,
+  RouteInstructionArgs,
+} from '../../src/generated/instructions/route';
+import { METAPLEX_PROGRAM_ID } from '../utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('freeze sol payment', async (t) => {
+  // deploys a candy guard with the freeze sol payment guard
+
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.freezeSolPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const [freezePda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('freeze_escrow'),
+      payerPair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  // mint (as a minter) before the freeze escrow is initialized
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findAssociatedTokenAccountPda(mintForMinter.publicKey, minterKeypair.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+
+  await minterMintTx.assertError(t, /Freeze must be initialized/i);
+
+  // initializes the freeze escrow (freeze period of 1 day)
+
+  const freezePeriod = Buffer.alloc(8);
+  i64.write(freezePeriod, 0, 86400);
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard,
+    candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: Buffer.concat([Buffer.from([FreezeInstruction.Initialize]), freezePeriod]),
+    },
+    label: null,
+  };
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const initializeTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(routeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await initializeTx.assertSuccess(t);
+
+  // mint (as a minter) – the payment is held in the freeze escrow
+
+  const escrowBalance = await connection.getBalance(freezePda);
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findAssociatedTokenAccountPda(mintForMinter2.publicKey, minterKeypair.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+
+  await minterMintTx2.assertSuccess(t);
+
+  t.equal(
+    (await connection.getBalance(freezePda)) - escrowBalance,
+    100000000,
+    'freeze escrow received the payment',
+  );
+
+  // the NFT cannot be thawed before the end of the freeze period
+
+  const thawArgs: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: Buffer.from([FreezeInstruction.Thaw]),
+    },
+    label: null,
+  };
+
+  const thawIx = createRouteInstruction(accounts, thawArgs);
+  thawIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: mintForMinter2.publicKey,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: minterKeypair.publicKey,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: findAssociatedTokenAccountPda(mintForMinter2.publicKey, minterKeypair.publicKey),
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: findMasterEditionV2Pda(mintForMinter2.publicKey),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: METAPLEX_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const thawTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(thawIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await thawTx.assertError(t, /Thaw is not enabled/i);
+
+  // the funds cannot be unlocked while there are frozen NFTs
+
+  const unlockArgs: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: Buffer.from([FreezeInstruction.UnlockFunds]),
+    },
+    label: null,
+  };
+
+  const unlockIx = createRouteInstruction(accounts, unlockArgs);
+  unlockIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+
+  const unlockTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(unlockIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await unlockTx.assertError(t, /Unlock is not enabled/i);
+});
+
+test('freeze sol payment (invalid freeze period)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.freezeSolPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const [freezePda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('freeze_escrow'),
+      payerPair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  // freeze period of 31 days (maximum is 30 days)
+
+  const freezePeriod = Buffer.alloc(8);
+  i64.write(freezePeriod, 0, 31 * 86400);
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard,
+    candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeSolPayment,
+      data: Buffer.concat([Buffer.from([FreezeInstruction.Initialize]), freezePeriod]),
+    },
+    label: null,
+  };
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const tx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(routeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await tx.assertError(t, /Maximum freeze period exceeded/i);
+});
@@ -0,0 +1,302 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { i64 } from '@metaplex-foundation/beet';
+import { findAssociatedTokenAccountPda } from '@metaplex-foundation/js';
+import { AccountMeta, PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import {
+  ASSOCIATED_TOKEN_PROGRAM_ID,
+  createMint,
+  getAccount,
+  getAssociatedTokenAddress,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { BN } from 'bn.js';
+import { FreezeInstruction, GuardType, PROGRAM_ID } from '../../src/generated';
+import {
+  createRouteInstruction,
+  RouteInstructionAccounts,
+  RouteInstructionArgs,
+} from '../../src/generated/instructions/route';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('freeze token payment', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // creates the payment token: the minter receives 10 tokens and the
+  // authority receives the payments
+
+  const tokenMint = await createMint(connection, payerPair, payerPair.publicKey, null, 0);
+  const destinationAta = await getOrCreateAssociatedTokenAccount(
+    connection,
+    payerPair,
+    tokenMint,
+    payerPair.publicKey,
+  );
+  const minterAta = await getOrCreateAssociatedTokenAccount(
+    connection,
+    payerPair,
+    tokenMint,
+    minterKeypair.publicKey,
+  );
+  await mintTo(connection, payerPair, tokenMint, minterAta.address, payerPair, 10);
+
+  const data = newCandyGuardData();
+  data.default.freezeTokenPayment = {
+    amount: new BN(5),
+    mint: tokenMint,
+    destinationAta: destinationAta.address,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const [freezePda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('freeze_escrow'),
+      destinationAta.address.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+  const freezeAta = await getAssociatedTokenAddress(tokenMint, freezePda, true);
+
+  const paymentAccounts = (mint: PublicKey): AccountMeta[] => [
+    {
+      pubkey: freezePda,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: findAssociatedTokenAccountPda(mint, minterKeypair.publicKey),
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: minterAta.address,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: freezeAta,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: tokenMint,
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+
+  // mint (as a minter) before the freeze escrow is initialized
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    paymentAccounts(mintForMinter.publicKey),
+  );
+  await minterMintTx.assertError(t, /Freeze must be initialized/i);
+
+  // initializes the freeze escrow (freeze period of 1 day)
+
+  const freezePeriod = Buffer.alloc(8);
+  i64.write(freezePeriod, 0, 86400);
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard,
+    candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeTokenPayment,
+      data: Buffer.concat([Buffer.from([FreezeInstruction.Initialize]), freezePeriod]),
+    },
+    label: null,
+  };
+
+  const routeIx = createRouteInstruction(accounts, args);
+  routeIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: false,
+      },
+      {
+        pubkey: SystemProgram.programId,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: freezeAta,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: tokenMint,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: ASSOCIATED_TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: destinationAta.address,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const initializeTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(routeIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await initializeTx.assertSuccess(t);
+
+  // mint (as a minter) – the tokens are held in the freeze escrow
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    paymentAccounts(mintForMinter2.publicKey),
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  const escrow = await getAccount(connection, freezeAta);
+  t.equal(escrow.amount.toString(), '5', 'freeze escrow received the tokens');
+
+  // the funds cannot be unlocked while there are frozen NFTs
+
+  const unlockArgs: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeTokenPayment,
+      data: Buffer.from([FreezeInstruction.UnlockFunds]),
+    },
+    label: null,
+  };
+
+  const unlockIx = createRouteInstruction(accounts, unlockArgs);
+  unlockIx.keys.push(
+    ...[
+      {
+        pubkey: freezePda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: payerPair.publicKey,
+        isSigner: true,
+        isWritable: true,
+      },
+      {
+        pubkey: destinationAta.address,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: freezeAta,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: TOKEN_PROGRAM_ID,
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: tokenMint,
+        isSigner: false,
+        isWritable: false,
+      },
+    ],
+  );
+
+  const unlockTx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(unlockIx),
+    [payerPair],
+    'tx: Route',
+  );
+  await unlockTx.assertError(t, /Unlock is not enabled/i);
+});
+
+test('freeze token payment (not enabled)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1662479807,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const accounts: RouteInstructionAccounts = {
+    candyGuard,
+    candyMachine,
+    payer: payerPair.publicKey,
+  };
+
+  const args: RouteInstructionArgs = {
+    args: {
+      guard: GuardType.FreezeTokenPayment,
+      data: Buffer.from([FreezeInstruction.UnlockFunds]),
+    },
+    label: null,
+  };
+
+  const tx = fstTxHandler.sendAndConfirmTransaction(
+    new Transaction().add(createRouteInstruction(accounts, args)),
+    [payerPair],
+    'tx: Route',
+  );
+  await tx.assertError(t, /Freeze guard must be enabled/i);
+});
@@ -1,7 +1,12 @@
 import test from 'tape';
 import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
-import { PublicKey } from '@solana/web3.js';
-import { PROGRAM_ID } from '../../src/generated';
+import { PublicKey, Transaction } from '@solana/web3.js';
+import { GuardType, PROGRAM_ID } from '../../src/generated';
+import {
+  createRouteInstruction,
+  RouteInstructionAccounts,
+  RouteInstructionArgs,
+} from '../../src/generated/instructions/route';
 
 const API = new InitTransactions();
 
@@ -272,3 +277,149 @@ test('mint limit (limit = 2)', async (t) => {
 
   await minterMintTx4.assertSuccess(t);
 });
+
+test('mint limit (close counter)', async (t) => {
+  // deploys a candy guard with a mint limit
+
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const {
+    fstTxHandler: delegateHandler,
+    authorityPair: delegatePair,
+    connection: delegateConnection,
+  } = await API.authority();
+
+  const data = newCandyGuardData();
+  data.default.mintLimit = {
+    id: 0,
+    limit: 1,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const [mintCounterPda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('mint_limit'),
+      new Uint8Array([0]),
+      minterKeypair.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account 1 (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: mintCounterPda,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // grants the delegate the close of mint counters
+
+  const { tx: createDelegateTx, delegateRecord } = await API.createDelegate(
+    t,
+    candyGuard,
+    delegatePair.publicKey,
+    { __kind: 'CloseCounters' },
+    payerPair,
+    fstTxHandler,
+  );
+  await createDelegateTx.assertSuccess(t);
+
+  const closeCounterTx = (withRecord: boolean) => {
+    const accounts: RouteInstructionAccounts = {
+      candyGuard,
+      candyMachine,
+      payer: delegatePair.publicKey,
+    };
+
+    const args: RouteInstructionArgs = {
+      args: {
+        guard: GuardType.MintLimit,
+        data: minterKeypair.publicKey.toBuffer(),
+      },
+      label: null,
+    };
+
+    const routeIx = createRouteInstruction(accounts, args);
+    routeIx.keys.push(
+      {
+        pubkey: mintCounterPda,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: delegatePair.publicKey,
+        isSigner: true,
+        isWritable: true,
+      },
+    );
+
+    if (withRecord) {
+      routeIx.keys.push({
+        pubkey: delegateRecord,
+        isSigner: false,
+        isWritable: false,
+      });
+    }
+
+    const tx = new Transaction().add(routeIx);
+    return delegateHandler.sendAndConfirmTransaction(tx, [delegatePair], 'tx: Route');
+  };
+
+  // the delegate cannot close the counter without the delegate record
+
+  await closeCounterTx(false).assertError(t, /not the authority or a delegate/i);
+
+  // the delegate closes the counter
+
+  await closeCounterTx(true).assertSuccess(t);
+  t.equal(await delegateConnection.getAccountInfo(mintCounterPda), null, 'mint counter closed');
+
+  // the minter can mint again
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: mintCounterPda,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx2.assertSuccess(t);
+});
@@ -0,0 +1,55 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { Keypair } from '@solana/web3.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('program gate', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.programGate = {
+    additional: [],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter) – the mint transaction only uses the default programs
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertSuccess(t);
+});
+
+test('program gate (maximum number of programs)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.programGate = {
+    additional: Array.from({ length: 6 }, () => Keypair.generate().publicKey),
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /Maximum number of additional programs exceeded/i);
+});
@@ -0,0 +1,54 @@
+import test from 'tape';
+import spok from 'spok';
+import { InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { Keypair } from '@solana/web3.js';
+import { DATA_OFFSET, spokSamePubkey } from '../utils';
+import { parseData } from '../../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('program guard (configuration)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const programId = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.programGuard = {
+    programId,
+    accounts: 2,
+    config: Buffer.from([1, 2, 3, 4]),
+  };
+
+  const { tx: initializeTx, candyGuard } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertSuccess(t);
+
+  // parse the guards configuration
+  const accountInfo = await connection.getAccountInfo(candyGuard);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.programGuard, {
+    programId: spokSamePubkey(programId),
+    accounts: 2,
+  });
+  t.deepEqual(
+    [...(candyGuardData.default.programGuard?.config ?? [])],
+    [1, 2, 3, 4],
+    'program guard config',
+  );
+});
+
+test('program guard (maximum configuration size)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.programGuard = {
+    programId: Keypair.generate().publicKey,
+    accounts: 0,
+    config: Buffer.alloc(65),
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /maximum size of the program guard configuration/i);
+});
@@ -0,0 +1,225 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { i64, u64 } from '@metaplex-foundation/beet';
+import { Connection, Ed25519Program, Keypair, PublicKey, Transaction } from '@solana/web3.js';
+import { createAssociatedTokenAccount, createMint, mintTo } from '@solana/spl-token';
+import { PROGRAM_ID } from '../../src/generated';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+/**
+ * Creates the NFT mint and token account in a separate transaction, since the mint
+ * transaction with the voucher instruction would exceed the transaction size limit.
+ */
+async function prepareNft(connection: Connection, payer: Keypair, mint: Keypair) {
+  await createMint(connection, payer, payer.publicKey, payer.publicKey, 0, mint);
+  const ata = await createAssociatedTokenAccount(
+    connection,
+    payer,
+    mint.publicKey,
+    payer.publicKey,
+  );
+  await mintTo(connection, payer, mint.publicKey, ata, payer, 1);
+}
+
+function voucherMessage(payer: PublicKey, candyMachine: PublicKey, expiry: number, nonce: number) {
+  const expiryBuffer = Buffer.alloc(8);
+  i64.write(expiryBuffer, 0, expiry);
+  const nonceBuffer = Buffer.alloc(8);
+  u64.write(nonceBuffer, 0, nonce);
+
+  return Buffer.concat([payer.toBuffer(), candyMachine.toBuffer(), expiryBuffer, nonceBuffer]);
+}
+
+test('signed voucher', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const voucherSigner = Keypair.generate();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signer: voucherSigner.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const nonce = 1;
+  const nonceBuffer = Buffer.alloc(8);
+  u64.write(nonceBuffer, 0, nonce);
+
+  const [noncePda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('voucher'),
+      voucherSigner.publicKey.toBuffer(),
+      nonceBuffer,
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  const voucherAccounts = [
+    {
+      pubkey: noncePda,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  // mint (as a minter) without a voucher
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    voucherAccounts,
+  );
+  await minterMintTx.assertError(t, /Missing voucher signature instruction/i);
+
+  // mint (as a minter) with a voucher
+
+  const voucherIx = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: voucherSigner.secretKey,
+    message: voucherMessage(
+      minter.publicKey,
+      candyMachine,
+      Math.floor(Date.now() / 1000) + 3600,
+      nonce,
+    ),
+  });
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  await prepareNft(minterConnection, minter, mintForMinter2);
+
+  const mintIx = await API.mintInstruction(
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterConnection,
+    voucherAccounts,
+  );
+
+  const voucherTx = minterHandler.sendAndConfirmTransaction(
+    new Transaction().add(voucherIx, mintIx),
+    [minter],
+    'tx: Candy Guard Mint',
+  );
+  await voucherTx.assertSuccess(t);
+
+  // mint (as a minter) reusing the voucher
+
+  const [, mintForMinter3] = await amman.genLabeledKeypair('Mint Account 3 (minter)');
+  await prepareNft(minterConnection, minter, mintForMinter3);
+
+  const reuseMintIx = await API.mintInstruction(
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter3,
+    minterConnection,
+    voucherAccounts,
+  );
+
+  const reuseTx = minterHandler.sendAndConfirmTransaction(
+    new Transaction().add(voucherIx, reuseMintIx),
+    [minter],
+    'tx: Candy Guard Mint',
+  );
+  await reuseTx.assertError(t, /Voucher has already been used/i);
+});
+
+test('signed voucher (expired)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const voucherSigner = Keypair.generate();
+
+  const data = newCandyGuardData();
+  data.default.signedVoucher = {
+    signer: voucherSigner.publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const nonce = 1;
+  const nonceBuffer = Buffer.alloc(8);
+  u64.write(nonceBuffer, 0, nonce);
+
+  const [noncePda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('voucher'),
+      voucherSigner.publicKey.toBuffer(),
+      nonceBuffer,
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+
+  // mint (as a minter) with a voucher that expired an hour ago
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const voucherIx = Ed25519Program.createInstructionWithPrivateKey({
+    privateKey: voucherSigner.secretKey,
+    message: voucherMessage(
+      minter.publicKey,
+      candyMachine,
+      Math.floor(Date.now() / 1000) - 3600,
+      nonce,
+    ),
+  });
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  await prepareNft(minterConnection, minter, mintForMinter);
+
+  const mintIx = await API.mintInstruction(
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterConnection,
+    [
+      {
+        pubkey: noncePda,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+
+  const tx = minterHandler.sendAndConfirmTransaction(
+    new Transaction().add(voucherIx, mintIx),
+    [minter],
+    'tx: Candy Guard Mint',
+  );
+  await tx.assertError(t, /Voucher expired/i);
+});
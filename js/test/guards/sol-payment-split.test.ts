@@ -0,0 +1,109 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { Keypair } from '@solana/web3.js';
+import { BN } from 'bn.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('sol payment split', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const first = Keypair.generate().publicKey;
+  const second = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.solPaymentSplit = {
+    lamports: new BN(100000000),
+    destinations: [
+      {
+        address: first,
+        basisPoints: 7000,
+      },
+      {
+        address: second,
+        basisPoints: 3000,
+      },
+    ],
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // missing destination accounts
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /Missing expected remaining account/i);
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: first,
+        isSigner: false,
+        isWritable: true,
+      },
+      {
+        pubkey: second,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  t.equal(await connection.getBalance(first), 70000000, 'first destination share');
+  t.equal(await connection.getBalance(second), 30000000, 'second destination share');
+});
+
+test('sol payment split (invalid shares)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.solPaymentSplit = {
+    lamports: new BN(100000000),
+    destinations: [
+      {
+        address: Keypair.generate().publicKey,
+        basisPoints: 7000,
+      },
+      {
+        address: Keypair.generate().publicKey,
+        basisPoints: 2000,
+      },
+    ],
+  };
+
+  const { tx: initializeTx } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await initializeTx.assertError(t, /must add up to 10000/i);
+});
@@ -0,0 +1,162 @@
+import test, { Test } from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from '../setup';
+import { AccountMeta, PublicKey } from '@solana/web3.js';
+import {
+  createMint,
+  getAccount,
+  getOrCreateAssociatedTokenAccount,
+  mintTo,
+  TOKEN_2022_PROGRAM_ID,
+  TOKEN_PROGRAM_ID,
+} from '@solana/spl-token';
+import { BN } from 'bn.js';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+async function tokenPayment(t: Test, programId: PublicKey) {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  // creates the payment token: the minter receives 10 tokens and the
+  // authority receives the payments
+
+  const tokenMint = await createMint(
+    connection,
+    payerPair,
+    payerPair.publicKey,
+    null,
+    0,
+    undefined,
+    undefined,
+    programId,
+  );
+  const destinationAta = await getOrCreateAssociatedTokenAccount(
+    connection,
+    payerPair,
+    tokenMint,
+    payerPair.publicKey,
+    false,
+    undefined,
+    undefined,
+    programId,
+  );
+  const minterAta = await getOrCreateAssociatedTokenAccount(
+    connection,
+    payerPair,
+    tokenMint,
+    minter.publicKey,
+    false,
+    undefined,
+    undefined,
+    programId,
+  );
+  await mintTo(
+    connection,
+    payerPair,
+    tokenMint,
+    minterAta.address,
+    payerPair,
+    10,
+    [],
+    undefined,
+    programId,
+  );
+
+  const data = newCandyGuardData();
+  data.default.tokenPayment = {
+    amount: new BN(5),
+    tokenMint,
+    destinationAta: destinationAta.address,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const paymentAccounts: AccountMeta[] = [
+    {
+      pubkey: minterAta.address,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: destinationAta.address,
+      isSigner: false,
+      isWritable: true,
+    },
+    {
+      pubkey: tokenMint,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: programId,
+      isSigner: false,
+      isWritable: false,
+    },
+  ];
+
+  // mint (as a minter)
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    paymentAccounts,
+  );
+  await minterMintTx.assertSuccess(t);
+
+  const destination = await getAccount(connection, destinationAta.address, undefined, programId);
+  t.equal(destination.amount.toString(), '5', 'destination received the tokens');
+
+  // mint (as a minter) – not enough tokens for a third mint
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    paymentAccounts,
+  );
+  await minterMintTx2.assertSuccess(t);
+
+  const [, mintForMinter3] = await amman.genLabeledKeypair('Mint Account 3 (minter)');
+  const { tx: minterMintTx3 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mintForMinter3,
+    minterHandler,
+    minterConnection,
+    paymentAccounts,
+  );
+  await minterMintTx3.assertError(t, /Not enough tokens/i);
+}
+
+test('token payment (spl-token)', async (t) => {
+  await tokenPayment(t, TOKEN_PROGRAM_ID);
+});
+
+test('token payment (Token-2022)', async (t) => {
+  await tokenPayment(t, TOKEN_2022_PROGRAM_ID);
+});
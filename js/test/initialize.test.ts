@@ -1,7 +1,7 @@
 import test from 'tape';
 import spok from 'spok';
 import { BN } from 'bn.js';
-import { newCandyGuardData, InitTransactions, killStuckProcess } from './setup';
+import { newCandyGuardData, newGuardSet, InitTransactions, killStuckProcess } from './setup';
 import { CandyGuard } from '../src/generated';
 import { DATA_OFFSET, spokSameBignum, spokSamePubkey } from './utils';
 import { parseData } from '../src';
@@ -92,3 +92,100 @@ test('initialize: new candy guard (with guards)', async (t) => {
     signerKey: spokSamePubkey(payerPair.publicKey),
   });
 });
+
+test('initialize: new candy guard (upper feature bits)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1663965742,
+  };
+  data.default.gatekeeper = {
+    gatekeeperNetwork: payerPair.publicKey,
+    expireOnUse: true,
+  };
+  data.default.allocation = {
+    id: 1,
+    limit: 5,
+  };
+  data.default.signedVoucher = {
+    signer: payerPair.publicKey,
+  };
+  data.default.combinator = {
+    branches: [new BN(1).shln(3)],
+    negated: 0,
+  };
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  // executes the transaction
+  await transaction.assertSuccess(t);
+
+  // parse the guards configuration
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.startDate, {
+    date: spokSameBignum(data.default.startDate.date),
+  });
+
+  spok(t, candyGuardData.default.gatekeeper, {
+    gatekeeperNetwork: spokSamePubkey(payerPair.publicKey),
+    expireOnUse: true,
+  });
+
+  spok(t, candyGuardData.default.allocation, {
+    id: 1,
+    limit: 5,
+  });
+
+  spok(t, candyGuardData.default.signedVoucher, {
+    signer: spokSamePubkey(payerPair.publicKey),
+  });
+
+  t.equal(candyGuardData.default.combinator?.branches.length, 1, 'combinator branches');
+  spok(t, candyGuardData.default.combinator, {
+    negated: spokSameBignum(0),
+  });
+});
+
+test('initialize: new candy guard (group labels)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  // labels are stored with their length, up to 32 characters
+  const labels = ['OG', 'OGplus', 'public-sale-with-a-long-label-32'];
+
+  const data = newCandyGuardData();
+  data.groups = labels.map((label) => ({ label, guards: newGuardSet() }));
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  // executes the transaction
+  await transaction.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  t.deepEqual(candyGuardData.groups?.map((group) => group.label), labels, 'group labels');
+});
+
+test('initialize: new candy guard (duplicate group labels)', async (t) => {
+  const { fstTxHandler, payerPair } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.groups = [
+    { label: 'OG', guards: newGuardSet() },
+    { label: 'OG', guards: newGuardSet() },
+  ];
+
+  const { tx: transaction } = await API.initialize(t, data, payerPair, fstTxHandler);
+  await transaction.assertError(t, /Group label already in use/i);
+});
@@ -0,0 +1,120 @@
+import test from 'tape';
+import spok from 'spok';
+import { BN } from 'bn.js';
+import * as beet from '@metaplex-foundation/beet';
+import { newCandyGuardData, InitTransactions, killStuckProcess } from './setup';
+import { DATA_OFFSET, spokSameBignum } from './utils';
+import { LAYOUT_VERSION, parseData, parseLayoutVersion, parsePendingAuthority } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('migrate: candy guard at the current layout version', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1663965742,
+  };
+
+  const { tx: transaction, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  // executes the transaction
+  await transaction.assertSuccess(t);
+
+  let accountInfo = await connection.getAccountInfo(address);
+  t.equal(parseLayoutVersion(accountInfo!.data.subarray(DATA_OFFSET)), LAYOUT_VERSION);
+
+  // migrating an account at the current version does not change the data
+  const { tx: migrateTx } = await API.migrate(t, address, payerPair, fstTxHandler);
+  await migrateTx.assertSuccess(t, [/already at version/i]);
+
+  accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default.startDate, {
+    date: spokSameBignum(data.default.startDate.date),
+  });
+});
+
+test('migrate: parse guards data without a layout header', async (t) => {
+  // guards data created before the layout was versioned: the feature flags of the
+  // default guard set (allow list), the merkle root and the number of groups
+  const features = Buffer.alloc(8);
+  beet.u64.write(features, 0, new BN(1).shln(8));
+  const merkleRoot = Buffer.alloc(32, 1);
+  const groups = Buffer.alloc(4);
+
+  const buffer = Buffer.concat([features, merkleRoot, groups]);
+
+  t.equal(parseLayoutVersion(buffer), 0, 'layout version');
+
+  const candyGuardData = parseData(buffer);
+
+  spok(t, candyGuardData.default.allowList, {
+    merkleRoot: [...merkleRoot],
+    proofTtl: null,
+  });
+  t.equal(candyGuardData.groups, null, 'no groups');
+});
+
+test('migrate: parse guards data with a version 1 layout header', async (t) => {
+  // the header of version 1 does not include the pending authority
+  const header = Buffer.alloc(8);
+  beet.u64.write(header, 0, new BN(1).shln(63).addn(1));
+  // feature flags of the default guard set (start date) and its configuration
+  const features = Buffer.alloc(8);
+  beet.u64.write(features, 0, new BN(1).shln(3));
+  const startDate = Buffer.alloc(8);
+  beet.i64.write(startDate, 0, 1663965742);
+  const groups = Buffer.alloc(4);
+
+  const buffer = Buffer.concat([header, features, startDate, groups]);
+
+  t.equal(parseLayoutVersion(buffer), 1, 'layout version');
+  t.equal(parsePendingAuthority(buffer), null, 'no pending authority');
+
+  const candyGuardData = parseData(buffer);
+
+  spok(t, candyGuardData.default.startDate, {
+    date: spokSameBignum(1663965742),
+  });
+  t.equal(candyGuardData.groups, null, 'no groups');
+});
+
+test('migrate: parse guards data with zero-padded group labels', async (t) => {
+  // the header of version 2 includes the pending authority (not set)
+  const header = Buffer.alloc(40);
+  beet.u64.write(header, 0, new BN(1).shln(63).addn(2));
+  // default guard set without guards and one group (start date)
+  const features = Buffer.alloc(8);
+  const groups = Buffer.alloc(4);
+  beet.u32.write(groups, 0, 1);
+  const label = Buffer.alloc(6);
+  label.write('OG');
+  const groupFeatures = Buffer.alloc(8);
+  beet.u64.write(groupFeatures, 0, new BN(1).shln(3));
+  const startDate = Buffer.alloc(8);
+  beet.i64.write(startDate, 0, 1663965742);
+
+  const buffer = Buffer.concat([header, features, groups, label, groupFeatures, startDate]);
+
+  t.equal(parseLayoutVersion(buffer), 2, 'layout version');
+
+  const candyGuardData = parseData(buffer);
+
+  t.equal(candyGuardData.groups?.length, 1, 'number of groups');
+  spok(t, candyGuardData.groups?.[0], {
+    label: 'OG',
+    guards: {
+      startDate: {
+        date: spokSameBignum(1663965742),
+      },
+    },
+  });
+});
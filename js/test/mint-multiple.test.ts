@@ -0,0 +1,192 @@
+import test from 'tape';
+import { amman, InitTransactions, killStuckProcess, newCandyGuardData } from './setup';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BN } from 'bn.js';
+import { CandyMachine } from '@metaplex-foundation/mpl-candy-machine-core';
+import { PROGRAM_ID } from '../src/generated';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('mint multiple: sol payment', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const destination = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint 3 items (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const mints: Keypair[] = [];
+  for (let i = 0; i < 3; i++) {
+    const [, mint] = await amman.genLabeledKeypair(`Mint Account ${i + 1} (minter)`);
+    mints.push(mint);
+  }
+
+  const { tx: minterMintTx } = await API.mintMultiple(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mints,
+    minterHandler,
+    minterConnection,
+    [
+      {
+        pubkey: destination,
+        isSigner: false,
+        isWritable: true,
+      },
+    ],
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // the payment is charged for each item
+  t.equal(await connection.getBalance(destination), 300000000, 'payment scaled by quantity');
+
+  const candyMachineObject = await CandyMachine.fromAccountAddress(connection, candyMachine);
+  t.equal(new BN(candyMachineObject.itemsRedeemed).toNumber(), 3, 'items redeemed');
+});
+
+test('mint multiple: mint limit', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.mintLimit = {
+    id: 0,
+    limit: 2,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const [mintCounterPda] = await PublicKey.findProgramAddress(
+    [
+      Buffer.from('mint_limit'),
+      new Uint8Array([0]),
+      minter.publicKey.toBuffer(),
+      candyGuard.toBuffer(),
+      candyMachine.toBuffer(),
+    ],
+    PROGRAM_ID,
+  );
+  const mintLimitAccounts = [
+    {
+      pubkey: mintCounterPda,
+      isSigner: false,
+      isWritable: true,
+    },
+  ];
+
+  // limit is 2, minting 3 items should fail
+
+  const mints: Keypair[] = [];
+  for (let i = 0; i < 3; i++) {
+    const [, mint] = await amman.genLabeledKeypair(`Mint Account ${i + 1} (minter)`);
+    mints.push(mint);
+  }
+
+  const { tx: minterMintTx } = await API.mintMultiple(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mints,
+    minterHandler,
+    minterConnection,
+    mintLimitAccounts,
+  );
+  await minterMintTx.assertError(t, /maximum number of allowed mints/i);
+
+  // limit is 2, minting 2 items should succeed
+
+  const otherMints: Keypair[] = [];
+  for (let i = 0; i < 2; i++) {
+    const [, mint] = await amman.genLabeledKeypair(`Mint Account ${i + 4} (minter)`);
+    otherMints.push(mint);
+  }
+
+  const { tx: minterMintTx2 } = await API.mintMultiple(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    otherMints,
+    minterHandler,
+    minterConnection,
+    mintLimitAccounts,
+  );
+  await minterMintTx2.assertSuccess(t);
+});
+
+test('mint multiple: guard without quantity support', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.programGuard = {
+    programId: Keypair.generate().publicKey,
+    accounts: 0,
+    config: Buffer.from([]),
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minter,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const mints: Keypair[] = [];
+  for (let i = 0; i < 2; i++) {
+    const [, mint] = await amman.genLabeledKeypair(`Mint Account ${i + 1} (minter)`);
+    mints.push(mint);
+  }
+
+  const { tx: minterMintTx } = await API.mintMultiple(
+    t,
+    candyGuard,
+    candyMachine,
+    minter,
+    mints,
+    minterHandler,
+    minterConnection,
+  );
+  await minterMintTx.assertError(t, /does not support minting multiple items/i);
+});
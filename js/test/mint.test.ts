@@ -4,6 +4,9 @@ import { CandyMachineHelper } from './utils';
 import { AccountMeta } from '@solana/web3.js';
 import { BN } from 'bn.js';
 
+// marker to select the group automatically when no label is specified
+const AUTO_SELECT_MARKER = 255;
+
 const API = new InitTransactions();
 const HELPER = new CandyMachineHelper();
 
@@ -323,6 +326,112 @@ test('mint from group', async (t) => {
   await minterMintTx2.assertSuccess(t);
 });
 
+test('mint from group (automatic selection)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  // date of the 'default' guard is way in the future
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 64091606400,
+  };
+  data.groups = [];
+
+  // VIP (only the authority is allowed to mint)
+  const vipGroup = newGuardSet();
+  vipGroup.startDate = {
+    date: 1662394820,
+  };
+  vipGroup.addressGate = {
+    address: payerPair.publicKey,
+  };
+  data.groups.push({
+    label: 'VIP',
+    guards: vipGroup,
+  });
+
+  // OGs
+  const ogGroup = newGuardSet();
+  ogGroup.startDate = {
+    date: 1662394820,
+  };
+  ogGroup.solPayment = {
+    lamports: new BN(50000000),
+    destination: payerPair.publicKey,
+  };
+  data.groups.push({
+    label: 'OGs',
+    guards: ogGroup,
+  });
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const accounts: AccountMeta[] = [];
+  accounts.push({
+    pubkey: payerPair.publicKey,
+    isSigner: false,
+    isWritable: true,
+  });
+
+  // the mint args start with the auto select marker, followed by the number of
+  // remaining accounts of each group: VIP (0) and OGs (1)
+
+  const balance = await connection.getBalance(payerPair.publicKey);
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Buffer.from([AUTO_SELECT_MARKER, 0, 1]),
+    null,
+  );
+
+  await minterMintTx.assertSuccess(t);
+
+  t.equal(
+    (await connection.getBalance(payerPair.publicKey)) - balance,
+    50000000,
+    'OGs group selected',
+  );
+
+  // the OGs section does not include the payment destination
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    accounts,
+    Buffer.from([AUTO_SELECT_MARKER, 1, 0]),
+    null,
+  );
+
+  await minterMintTx2.assertError(t, /None of the groups is eligible/i);
+});
+
 test('mint from group (bot tax)', async (t) => {
   // deploys a candy guard with a mint limit
 
@@ -428,3 +537,77 @@ test('mint from group (bot tax)', async (t) => {
 
   await minterMintTx2.assertSuccess(t);
 });
+
+test('mint from group (exact label match)', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.groups = [];
+
+  // the group with the longer label is not live
+  const ogPlusGroup = newGuardSet();
+  ogPlusGroup.startDate = {
+    date: 64091606400,
+  };
+  data.groups.push({
+    label: 'OGplus',
+    guards: ogPlusGroup,
+  });
+
+  const ogGroup = newGuardSet();
+  ogGroup.startDate = {
+    date: 1662394820,
+  };
+  data.groups.push({
+    label: 'OG',
+    guards: ogGroup,
+  });
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // mint (as a minter)
+
+  const {
+    fstTxHandler: minterHandler,
+    minterPair: minterKeypair,
+    connection: minterConnection,
+  } = await API.minter();
+
+  const [, mintForMinter] = await amman.genLabeledKeypair('Mint Account (minter)');
+  const { tx: minterMintTx } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter,
+    minterHandler,
+    minterConnection,
+    null,
+    null,
+    'OG',
+  );
+  await minterMintTx.assertSuccess(t);
+
+  // a prefix of a label does not select the group
+
+  const [, mintForMinter2] = await amman.genLabeledKeypair('Mint Account 2 (minter)');
+  const { tx: minterMintTx2 } = await API.mint(
+    t,
+    candyGuard,
+    candyMachine,
+    minterKeypair,
+    mintForMinter2,
+    minterHandler,
+    minterConnection,
+    null,
+    null,
+    'OGp',
+  );
+  await minterMintTx2.assertError(t, /Group not found/i);
+});
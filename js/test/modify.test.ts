@@ -0,0 +1,171 @@
+import test from 'tape';
+import spok from 'spok';
+import { newCandyGuardData, newGuardSet, InitTransactions, killStuckProcess } from './setup';
+import { GuardType } from '../src/generated';
+import { DATA_OFFSET, spokSameBignum, spokSamePubkey } from './utils';
+import { BN } from 'bn.js';
+import { parseData } from '../src';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('modify: set and clear guards of the default guard set', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const data = newCandyGuardData();
+  data.default.startDate = {
+    date: 1663965742,
+  };
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  // enables the sol payment (the start date is not modified)
+
+  const guards = newGuardSet();
+  guards.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+
+  const { tx: setGuardsTx } = await API.setGuards(
+    t,
+    address,
+    null,
+    guards,
+    payerPair,
+    fstTxHandler,
+  );
+  await setGuardsTx.assertSuccess(t);
+
+  let accountInfo = await connection.getAccountInfo(address);
+  let candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  spok(t, candyGuardData.default, {
+    startDate: {
+      date: spokSameBignum(1663965742),
+    },
+    solPayment: {
+      lamports: spokSameBignum(100000000),
+      destination: spokSamePubkey(payerPair.publicKey),
+    },
+  });
+
+  // disables the start date
+
+  const { tx: clearGuardTx } = await API.clearGuard(
+    t,
+    address,
+    null,
+    GuardType.StartDate,
+    payerPair,
+    fstTxHandler,
+  );
+  await clearGuardTx.assertSuccess(t);
+
+  accountInfo = await connection.getAccountInfo(address);
+  candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  t.equal(candyGuardData.default.startDate, null, 'start date disabled');
+  spok(t, candyGuardData.default.solPayment, {
+    lamports: spokSameBignum(100000000),
+  });
+});
+
+test('modify: add, rename and remove groups', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const { tx: initializeTx, candyGuard: address } = await API.initialize(
+    t,
+    newCandyGuardData(),
+    payerPair,
+    fstTxHandler,
+  );
+  await initializeTx.assertSuccess(t);
+
+  // adds a group
+
+  const vipGroup = newGuardSet();
+  vipGroup.startDate = {
+    date: 1663965742,
+  };
+
+  const { tx: addGroupTx } = await API.addGroup(
+    t,
+    address,
+    'VIP',
+    vipGroup,
+    payerPair,
+    fstTxHandler,
+  );
+  await addGroupTx.assertSuccess(t);
+
+  const { tx: duplicateTx } = await API.addGroup(
+    t,
+    address,
+    'VIP',
+    newGuardSet(),
+    payerPair,
+    fstTxHandler,
+  );
+  await duplicateTx.assertError(t, /Group label already in use/i);
+
+  // renames the group
+
+  const { tx: renameGroupTx } = await API.renameGroup(
+    t,
+    address,
+    'VIP',
+    'OGs',
+    payerPair,
+    fstTxHandler,
+  );
+  await renameGroupTx.assertSuccess(t);
+
+  // sets a guard of the group
+
+  const guards = newGuardSet();
+  guards.endDate = {
+    date: 1675209600,
+  };
+
+  const { tx: setGuardsTx } = await API.setGuards(
+    t,
+    address,
+    'OGs',
+    guards,
+    payerPair,
+    fstTxHandler,
+  );
+  await setGuardsTx.assertSuccess(t);
+
+  const accountInfo = await connection.getAccountInfo(address);
+  const candyGuardData = parseData(accountInfo!.data.subarray(DATA_OFFSET));
+
+  t.deepEqual(candyGuardData.groups?.map((group) => group.label), ['OGs'], 'group labels');
+  spok(t, candyGuardData.groups?.[0].guards, {
+    startDate: {
+      date: spokSameBignum(1663965742),
+    },
+    endDate: {
+      date: spokSameBignum(1675209600),
+    },
+  });
+
+  // removes the group
+
+  const { tx: missingGroupTx } = await API.removeGroup(t, address, 'VIP', payerPair, fstTxHandler);
+  await missingGroupTx.assertError(t, /Group not found/i);
+
+  const { tx: removeGroupTx } = await API.removeGroup(t, address, 'OGs', payerPair, fstTxHandler);
+  await removeGroupTx.assertSuccess(t);
+
+  const removedInfo = await connection.getAccountInfo(address);
+  t.equal(parseData(removedInfo!.data.subarray(DATA_OFFSET)).groups, null, 'no groups');
+});
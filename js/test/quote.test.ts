@@ -0,0 +1,112 @@
+import test from 'tape';
+import spok from 'spok';
+import { InitTransactions, killStuckProcess, newCandyGuardData, newGuardSet } from './setup';
+import { Keypair } from '@solana/web3.js';
+import { createMint } from '@solana/spl-token';
+import { BN } from 'bn.js';
+import { spokSameBignum, spokSamePubkey } from './utils';
+
+const API = new InitTransactions();
+
+killStuckProcess();
+
+test('quote: default guards', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const tokenMint = await createMint(connection, payerPair, payerPair.publicKey, null, 0);
+
+  const data = newCandyGuardData();
+  data.default.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+  data.default.tokenPayment = {
+    amount: new BN(5),
+    tokenMint,
+    destinationAta: Keypair.generate().publicKey,
+  };
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // the mint of the token payment is required to compute the transfer fee
+  const quote = await API.quote(candyGuard, candyMachine, payerPair, connection, [
+    {
+      pubkey: tokenMint,
+      isSigner: false,
+      isWritable: false,
+    },
+  ]);
+
+  spok(t, quote, {
+    lamports: spokSameBignum(100000000),
+    nftPayment: null,
+    nftBurn: null,
+  });
+  t.equal(quote.tokens.length, 1, 'one token amount');
+  spok(t, quote.tokens[0], {
+    mint: spokSamePubkey(tokenMint),
+    amount: spokSameBignum(5),
+  });
+  t.equal(quote.tokenBurns.length, 0, 'no token burns');
+});
+
+test('quote: group', async (t) => {
+  const { fstTxHandler, payerPair, connection } = await API.payer();
+
+  const requiredCollection = Keypair.generate().publicKey;
+  const burnMint = Keypair.generate().publicKey;
+
+  const data = newCandyGuardData();
+  data.default.nftPayment = {
+    requiredCollection,
+    destination: payerPair.publicKey,
+  };
+  data.groups = [];
+
+  const vipGroup = newGuardSet();
+  vipGroup.solPayment = {
+    lamports: new BN(100000000),
+    destination: payerPair.publicKey,
+  };
+  vipGroup.freezeSolPayment = {
+    lamports: new BN(50000000),
+    destination: payerPair.publicKey,
+  };
+  vipGroup.tokenBurn = {
+    amount: new BN(2),
+    mint: burnMint,
+  };
+  data.groups.push({
+    label: 'VIP',
+    guards: vipGroup,
+  });
+
+  const { candyGuard, candyMachine } = await API.deploy(
+    t,
+    data,
+    payerPair,
+    fstTxHandler,
+    connection,
+  );
+
+  // the lamports of the guards are added together and the default guards are included
+  const quote = await API.quote(candyGuard, candyMachine, payerPair, connection, null, 'VIP');
+
+  spok(t, quote, {
+    lamports: spokSameBignum(150000000),
+    nftPayment: spokSamePubkey(requiredCollection),
+    nftBurn: null,
+  });
+  t.equal(quote.tokens.length, 0, 'no token amounts');
+  t.equal(quote.tokenBurns.length, 1, 'one token burn');
+  spok(t, quote.tokenBurns[0], {
+    mint: spokSamePubkey(burnMint),
+    amount: spokSameBignum(2),
+  });
+});
@@ -30,6 +30,16 @@ export function newGuardSet(): GuardSet {
     nftGate: null,
     nftBurn: null,
     tokenBurn: null,
+    freezeSolPayment: null,
+    freezeTokenPayment: null,
+    programGate: null,
+    allocation: null,
+    solPaymentSplit: null,
+    dutchAuction: null,
+    bondingCurve: null,
+    signedVoucher: null,
+    combinator: null,
+    programGuard: null,
   };
 }
 
@@ -6,6 +6,7 @@ import {
   PayerTransactionHandler,
 } from '@metaplex-foundation/amman-client';
 import {
+  ComputeBudgetProgram,
   Connection,
   Keypair,
   PublicKey,
@@ -22,24 +23,69 @@ import {
   CANDY_MACHINE_PROGRAM,
   CandyMachineHelper,
   getCandyGuardPDA,
+  getConfigBufferPDA,
+  getDelegateRecordPDA,
   METAPLEX_PROGRAM_ID,
 } from '../utils';
 import {
+  AcceptAuthorityInstructionAccounts,
+  AddGroupInstructionAccounts,
+  ApplyBufferInstructionAccounts,
   CandyGuardData,
+  CloseBufferInstructionAccounts,
+  CreateDelegateInstructionAccounts,
+  CreateDelegateInstructionArgs,
+  createAcceptAuthorityInstruction,
+  createAddGroupInstruction,
+  createApplyBufferInstruction,
+  createClearGuardInstruction,
+  createCloseBufferInstruction,
+  createCreateDelegateInstruction,
+  createEvaluateInstruction,
+  createInitializeBufferInstruction,
   createInitializeInstruction,
+  createMigrateInstruction,
   createMintInstruction,
+  createMintMultipleInstruction,
+  createQuoteInstruction,
+  createRemoveGroupInstruction,
+  createRenameGroupInstruction,
+  createRevokeDelegateInstruction,
+  createSetAuthorityInstruction,
+  createSetGuardsInstruction,
   createUnwrapInstruction,
   createUpdateInstruction,
   createWrapInstruction,
+  createWriteBufferInstruction,
+  DelegateRole,
+  EvaluateInstructionAccounts,
+  EvaluateInstructionArgs,
+  EvaluationResult,
+  evaluationResultBeet,
+  GuardSet,
+  GuardType,
+  InitializeBufferInstructionAccounts,
   InitializeInstructionAccounts,
   InitializeInstructionArgs,
+  MigrateInstructionAccounts,
   MintInstructionAccounts,
   MintInstructionArgs,
+  MintMultipleInstructionAccounts,
+  MintMultipleInstructionArgs,
+  PriceQuote,
+  priceQuoteBeet,
   PROGRAM_ID,
+  QuoteInstructionAccounts,
+  QuoteInstructionArgs,
+  RevokeDelegateInstructionAccounts,
+  SetAuthorityInstructionAccounts,
+  SetAuthorityInstructionArgs,
   UnwrapInstructionAccounts,
   UpdateInstructionAccounts,
   UpdateInstructionArgs,
   WrapInstructionAccounts,
+  WriteBufferInstructionAccounts,
+  WriteBufferInstructionArgs,
 } from '../../src/generated';
 import { CandyMachine } from '@metaplex-foundation/mpl-candy-machine-core';
 import {
@@ -199,6 +245,7 @@ export class InitTransactions {
     data: CandyGuardData,
     payer: Keypair,
     handler: PayerTransactionHandler,
+    delegateRecord?: PublicKey | null,
   ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
     const accounts: UpdateInstructionAccounts = {
       candyGuard,
@@ -211,13 +258,316 @@ export class InitTransactions {
       data,
     };
 
-    const tx = new Transaction().add(createUpdateInstruction(accounts, args));
+    const updateIx = createUpdateInstruction(accounts, args);
+    // the delegate record is required when the signer is a delegate
+    if (delegateRecord) {
+      updateIx.keys.push({ pubkey: delegateRecord, isSigner: false, isWritable: false });
+    }
+
+    const tx = new Transaction().add(updateIx);
 
     return {
       tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Update'),
     };
   }
 
+  async setGuards(
+    t: Test,
+    candyGuard: PublicKey,
+    label: string | null,
+    guards: GuardSet,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const tx = new Transaction().add(
+      createSetGuardsInstruction(this.modifyAccounts(candyGuard, authority), { label, guards }),
+    );
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Set Guards'),
+    };
+  }
+
+  async clearGuard(
+    t: Test,
+    candyGuard: PublicKey,
+    label: string | null,
+    guard: GuardType,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const tx = new Transaction().add(
+      createClearGuardInstruction(this.modifyAccounts(candyGuard, authority), { label, guard }),
+    );
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Clear Guard'),
+    };
+  }
+
+  async addGroup(
+    t: Test,
+    candyGuard: PublicKey,
+    label: string,
+    guards: GuardSet,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const tx = new Transaction().add(
+      createAddGroupInstruction(this.modifyAccounts(candyGuard, authority), { label, guards }),
+    );
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Add Group'),
+    };
+  }
+
+  async removeGroup(
+    t: Test,
+    candyGuard: PublicKey,
+    label: string,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const tx = new Transaction().add(
+      createRemoveGroupInstruction(this.modifyAccounts(candyGuard, authority), { label }),
+    );
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Remove Group'),
+    };
+  }
+
+  async renameGroup(
+    t: Test,
+    candyGuard: PublicKey,
+    label: string,
+    newLabel: string,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const tx = new Transaction().add(
+      createRenameGroupInstruction(this.modifyAccounts(candyGuard, authority), {
+        label,
+        newLabel,
+      }),
+    );
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Rename Group'),
+    };
+  }
+
+  async initializeBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; buffer: PublicKey }> {
+    const buffer = await getConfigBufferPDA(PROGRAM_ID, candyGuard);
+
+    const accounts: InitializeBufferInstructionAccounts = {
+      candyGuard,
+      authority: authority.publicKey,
+      buffer,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createInitializeBufferInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Initialize Buffer'),
+      buffer,
+    };
+  }
+
+  async writeBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    buffer: PublicKey,
+    offset: number,
+    data: Uint8Array,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: WriteBufferInstructionAccounts = {
+      candyGuard,
+      buffer,
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: WriteBufferInstructionArgs = {
+      offset,
+      data,
+    };
+
+    const tx = new Transaction().add(createWriteBufferInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Write Buffer'),
+    };
+  }
+
+  async applyBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    buffer: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: ApplyBufferInstructionAccounts = {
+      candyGuard,
+      buffer,
+      bufferAuthority: authority.publicKey,
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createApplyBufferInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Apply Buffer'),
+    };
+  }
+
+  async closeBuffer(
+    t: Test,
+    candyGuard: PublicKey,
+    buffer: PublicKey,
+    bufferAuthority: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: CloseBufferInstructionAccounts = {
+      candyGuard,
+      buffer,
+      authority: authority.publicKey,
+      bufferAuthority,
+    };
+
+    const tx = new Transaction().add(createCloseBufferInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Close Buffer'),
+    };
+  }
+
+  async createDelegate(
+    t: Test,
+    candyGuard: PublicKey,
+    delegate: PublicKey,
+    role: DelegateRole,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise; delegateRecord: PublicKey }> {
+    const delegateRecord = await getDelegateRecordPDA(PROGRAM_ID, candyGuard, delegate, role);
+
+    const accounts: CreateDelegateInstructionAccounts = {
+      candyGuard,
+      authority: authority.publicKey,
+      delegate,
+      delegateRecord,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const args: CreateDelegateInstructionArgs = {
+      role,
+    };
+
+    const tx = new Transaction().add(createCreateDelegateInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Create Delegate'),
+      delegateRecord,
+    };
+  }
+
+  async revokeDelegate(
+    t: Test,
+    candyGuard: PublicKey,
+    delegateRecord: PublicKey,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: RevokeDelegateInstructionAccounts = {
+      candyGuard,
+      authority: authority.publicKey,
+      delegateRecord,
+    };
+
+    const tx = new Transaction().add(createRevokeDelegateInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Revoke Delegate'),
+    };
+  }
+
+  async migrate(
+    t: Test,
+    candyGuard: PublicKey,
+    payer: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: MigrateInstructionAccounts = {
+      candyGuard,
+      authority: payer.publicKey,
+      payer: payer.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+
+    const tx = new Transaction().add(createMigrateInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Migrate'),
+    };
+  }
+
+  async setAuthority(
+    t: Test,
+    candyGuard: PublicKey,
+    newAuthority: PublicKey | null,
+    authority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: SetAuthorityInstructionAccounts = {
+      candyGuard,
+      authority: authority.publicKey,
+    };
+
+    const args: SetAuthorityInstructionArgs = {
+      newAuthority,
+    };
+
+    const tx = new Transaction().add(createSetAuthorityInstruction(accounts, args));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [authority], 'tx: Set Authority'),
+    };
+  }
+
+  async acceptAuthority(
+    t: Test,
+    candyGuard: PublicKey,
+    newAuthority: Keypair,
+    handler: PayerTransactionHandler,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const accounts: AcceptAuthorityInstructionAccounts = {
+      candyGuard,
+      newAuthority: newAuthority.publicKey,
+    };
+
+    const tx = new Transaction().add(createAcceptAuthorityInstruction(accounts));
+
+    return {
+      tx: handler.sendAndConfirmTransaction(tx, [newAuthority], 'tx: Accept Authority'),
+    };
+  }
+
   async mint(
     t: Test,
     candyGuard: PublicKey,
@@ -230,13 +580,254 @@ export class InitTransactions {
     mintArgs?: Uint8Array | null,
     label?: string | null,
   ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    const ixs = await this.nftInstructions(payer, mint, connection);
+    ixs.push(
+      await this.mintInstruction(
+        candyGuard,
+        candyMachine,
+        payer,
+        mint,
+        connection,
+        remainingAccounts,
+        mintArgs,
+        label,
+      ),
+    );
+
+    const tx = new Transaction().add(...ixs);
+
+    return { tx: handler.sendAndConfirmTransaction(tx, [payer, mint], 'tx: Candy Guard Mint') };
+  }
+
+  async mintMultiple(
+    t: Test,
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mints: Keypair[],
+    handler: PayerTransactionHandler,
+    connection: Connection,
+    remainingAccounts?: AccountMeta[] | null,
+    mintArgs?: Uint8Array | null,
+    label?: string | null,
+  ): Promise<{ tx: ConfirmedTransactionAssertablePromise }> {
+    // the NFTs are created in separate transactions, since a single transaction
+    // would exceed the transaction size limit
+    for (const mint of mints) {
+      const nftTx = new Transaction().add(...(await this.nftInstructions(payer, mint, connection)));
+      await handler
+        .sendAndConfirmTransaction(nftTx, [payer, mint], 'tx: Create NFT Mint')
+        .assertSuccess(t);
+    }
+
+    const [first, ...others] = mints;
+    const accounts: MintMultipleInstructionAccounts = await this.mintAccounts(
+      candyGuard,
+      candyMachine,
+      payer,
+      first,
+      connection,
+    );
+
+    const args: MintMultipleInstructionArgs = {
+      mintArgs: mintArgs ?? new Uint8Array(),
+      label: label ?? null,
+      quantity: mints.length,
+    };
+
+    const mintIx = createMintMultipleInstruction(accounts, args);
+    if (remainingAccounts) {
+      mintIx.keys.push(...remainingAccounts);
+    }
+    // accounts of the additional items follow the accounts of the guards
+    for (const mint of others) {
+      mintIx.keys.push(
+        { pubkey: mint.publicKey, isSigner: false, isWritable: true },
+        { pubkey: findMetadataPda(mint.publicKey), isSigner: false, isWritable: true },
+        { pubkey: findMasterEditionV2Pda(mint.publicKey), isSigner: false, isWritable: true },
+      );
+    }
+
+    const tx = new Transaction().add(
+      ComputeBudgetProgram.setComputeUnitLimit({ units: 1_400_000 }),
+      mintIx,
+    );
+
+    return { tx: handler.sendAndConfirmTransaction(tx, [payer], 'tx: Candy Guard Mint Multiple') };
+  }
+
+  async mintInstruction(
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mint: Keypair,
+    connection: Connection,
+    remainingAccounts?: AccountMeta[] | null,
+    mintArgs?: Uint8Array | null,
+    label?: string | null,
+  ): Promise<TransactionInstruction> {
+    const accounts = await this.mintAccounts(candyGuard, candyMachine, payer, mint, connection);
+
+    if (!mintArgs) {
+      mintArgs = new Uint8Array();
+    }
+
+    const args: MintInstructionArgs = {
+      mintArgs,
+      label: label ?? null,
+    };
+
+    const mintIx = createMintInstruction(accounts, args);
+    if (remainingAccounts) {
+      mintIx.keys.push(...remainingAccounts);
+    }
+
+    return mintIx;
+  }
+
+  async evaluate(
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mint: Keypair,
+    connection: Connection,
+    remainingAccounts?: AccountMeta[] | null,
+    mintArgs?: Uint8Array | null,
+    label?: string | null,
+  ): Promise<EvaluationResult> {
+    const accounts: EvaluateInstructionAccounts = await this.mintAccounts(
+      candyGuard,
+      candyMachine,
+      payer,
+      mint,
+      connection,
+    );
+
+    const args: EvaluateInstructionArgs = {
+      mintArgs: mintArgs ?? new Uint8Array(),
+      label: label ?? null,
+    };
+
+    const evaluateIx = createEvaluateInstruction(accounts, args);
+    if (remainingAccounts) {
+      evaluateIx.keys.push(...remainingAccounts);
+    }
+
+    const returnData = await this.simulate(evaluateIx, payer, connection);
+    const [result] = evaluationResultBeet.deserialize(returnData);
+
+    return result;
+  }
+
+  async quote(
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    connection: Connection,
+    remainingAccounts?: AccountMeta[] | null,
+    label?: string | null,
+  ): Promise<PriceQuote> {
+    const accounts: QuoteInstructionAccounts = {
+      candyGuard,
+      candyMachine,
+    };
+
+    const args: QuoteInstructionArgs = {
+      label: label ?? null,
+    };
+
+    const quoteIx = createQuoteInstruction(accounts, args);
+    if (remainingAccounts) {
+      quoteIx.keys.push(...remainingAccounts);
+    }
+
+    const returnData = await this.simulate(quoteIx, payer, connection);
+    const [quote] = priceQuoteBeet.deserialize(returnData);
+
+    return quote;
+  }
+
+  /**
+   * Simulates the instruction and returns the return data of the candy guard program.
+   */
+  private async simulate(
+    instruction: TransactionInstruction,
+    payer: Keypair,
+    connection: Connection,
+  ): Promise<Buffer> {
+    const tx = new Transaction().add(instruction);
+    tx.feePayer = payer.publicKey;
+    tx.recentBlockhash = (await connection.getLatestBlockhash()).blockhash;
+
+    const { value } = await connection.simulateTransaction(tx, [payer]);
+    if (value.err) {
+      throw new Error(`Simulation failed: ${JSON.stringify(value.err)}`);
+    }
+    // the return data is logged by the runtime
+    const prefix = `Program return: ${PROGRAM_ID.toBase58()} `;
+    const returnLog = value.logs?.find((log) => log.startsWith(prefix));
+    if (!returnLog) {
+      throw new Error('Missing return data');
+    }
+
+    return Buffer.from(returnLog.slice(prefix.length), 'base64');
+  }
+
+  /**
+   * Accounts of the instructions that modify the configuration in place (the
+   * authority also pays for the account resizing).
+   */
+  private modifyAccounts(candyGuard: PublicKey, authority: Keypair): AddGroupInstructionAccounts {
+    return {
+      candyGuard,
+      authority: authority.publicKey,
+      payer: authority.publicKey,
+      systemProgram: SystemProgram.programId,
+    };
+  }
+
+  /**
+   * Instructions to create the mint and token account of an NFT, holding one token.
+   */
+  private async nftInstructions(
+    payer: Keypair,
+    mint: Keypair,
+    connection: Connection,
+  ): Promise<TransactionInstruction[]> {
+    const nftTokenAccount = findAssociatedTokenAccountPda(mint.publicKey, payer.publicKey);
+
+    return [
+      SystemProgram.createAccount({
+        fromPubkey: payer.publicKey,
+        newAccountPubkey: mint.publicKey,
+        lamports: await connection.getMinimumBalanceForRentExemption(MintLayout.span),
+        space: MintLayout.span,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      createInitializeMintInstruction(mint.publicKey, 0, payer.publicKey, payer.publicKey),
+      createAssociatedTokenAccountInstruction(
+        payer.publicKey,
+        nftTokenAccount,
+        payer.publicKey,
+        mint.publicKey,
+      ),
+      createMintToInstruction(mint.publicKey, nftTokenAccount, payer.publicKey, 1, []),
+    ];
+  }
+
+  private async mintAccounts(
+    candyGuard: PublicKey,
+    candyMachine: PublicKey,
+    payer: Keypair,
+    mint: Keypair,
+    connection: Connection,
+  ): Promise<MintInstructionAccounts> {
     // candy machine object
     const candyMachineObject = await CandyMachine.fromAccountAddress(connection, candyMachine);
 
     // PDAs required for the mint
     const nftMetadata = findMetadataPda(mint.publicKey);
     const nftMasterEdition = findMasterEditionV2Pda(mint.publicKey);
-    const nftTokenAccount = findAssociatedTokenAccountPda(mint.publicKey, payer.publicKey);
 
     const collectionMint = candyMachineObject.collectionMint;
     // retrieves the collection nft
@@ -251,7 +842,7 @@ export class InitTransactions {
     const collectionMetadata = findMetadataPda(collectionMint);
     const collectionMasterEdition = findMasterEditionV2Pda(collectionMint);
 
-    const accounts: MintInstructionAccounts = {
+    return {
       candyGuard,
       candyMachineProgram: CANDY_MACHINE_PROGRAM,
       candyMachine,
@@ -272,46 +863,6 @@ export class InitTransactions {
       recentSlothashes: SYSVAR_SLOT_HASHES_PUBKEY,
       instructionSysvarAccount: SYSVAR_INSTRUCTIONS_PUBKEY,
     };
-
-    if (!mintArgs) {
-      mintArgs = new Uint8Array();
-    }
-
-    const args: MintInstructionArgs = {
-      mintArgs,
-      label: label ?? null,
-    };
-
-    const ixs: TransactionInstruction[] = [];
-    ixs.push(
-      SystemProgram.createAccount({
-        fromPubkey: payer.publicKey,
-        newAccountPubkey: mint.publicKey,
-        lamports: await connection.getMinimumBalanceForRentExemption(MintLayout.span),
-        space: MintLayout.span,
-        programId: TOKEN_PROGRAM_ID,
-      }),
-    );
-    ixs.push(createInitializeMintInstruction(mint.publicKey, 0, payer.publicKey, payer.publicKey));
-    ixs.push(
-      createAssociatedTokenAccountInstruction(
-        payer.publicKey,
-        nftTokenAccount,
-        payer.publicKey,
-        mint.publicKey,
-      ),
-    );
-    ixs.push(createMintToInstruction(mint.publicKey, nftTokenAccount, payer.publicKey, 1, []));
-
-    const mintIx = createMintInstruction(accounts, args);
-    if (remainingAccounts) {
-      mintIx.keys.push(...remainingAccounts);
-    }
-    ixs.push(mintIx);
-
-    const tx = new Transaction().add(...ixs);
-
-    return { tx: handler.sendAndConfirmTransaction(tx, [payer, mint], 'tx: Candy Guard Mint') };
   }
 
   async deploy(
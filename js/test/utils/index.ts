@@ -7,6 +7,7 @@ import { CandyMachineData } from '@metaplex-foundation/mpl-candy-machine-core';
 import { Keypair, PublicKey } from '@solana/web3.js';
 import { BN } from 'bn.js';
 import { HIDDEN_SECTION } from './constants';
+import { DelegateRole } from '../../src/generated';
 
 export async function getCandyGuardPDA(programId: PublicKey, base: Keypair): Promise<PublicKey> {
   return await PublicKey.findProgramAddress(
@@ -17,6 +18,35 @@ export async function getCandyGuardPDA(programId: PublicKey, base: Keypair): Pro
   });
 }
 
+export async function getDelegateRecordPDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+  delegate: PublicKey,
+  role: DelegateRole,
+): Promise<PublicKey> {
+  // a delegate can hold one record of each role
+  const roleSeed = { Update: 0, Wrap: 1, CloseCounters: 2 }[role.__kind];
+
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('delegate'), candyGuard.toBuffer(), delegate.toBuffer(), Buffer.from([roleSeed])],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
+export async function getConfigBufferPDA(
+  programId: PublicKey,
+  candyGuard: PublicKey,
+): Promise<PublicKey> {
+  return await PublicKey.findProgramAddress(
+    [Buffer.from('config_buffer'), candyGuard.toBuffer()],
+    programId,
+  ).then((result) => {
+    return result[0];
+  });
+}
+
 export function getCandyMachineSpace(data: CandyMachineData): number {
   if (data.configLineSettings == null) {
     return HIDDEN_SECTION;
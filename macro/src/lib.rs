@@ -55,9 +55,9 @@ pub fn derive(input: TokenStream) -> TokenStream {
         if is_option_t(&f.ty) {
             let ty = unwrap_option_t(&f.ty);
             quote! {
-                let #name = if #ty::is_enabled(features) {
-                    cursor += #ty::size();
-                    #ty::load(data, cursor)?
+                let #name = if #ty::is_enabled(&features) {
+                    cursor += #ty::size_for(version);
+                    #ty::load_for(data, cursor, version)?
                 } else {
                     None
                 };
@@ -77,7 +77,6 @@ pub fn derive(input: TokenStream) -> TokenStream {
                     cursor += #ty::size();
                     if cursor <= data.len() {
                         #name.save(data, cursor - #ty::size())?;
-                        features = #ty::enable(features);
                     } else {
                         return err!(crate::errors::CandyGuardError::InvalidAccountSize);
                     }
@@ -102,6 +101,19 @@ pub fn derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let set_arm = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                GuardType::#ty => self.#name = other.#name.clone()
+            }
+        } else {
+            quote! {}
+        }
+    });
+
     let struct_fields = fields.iter().map(|f| {
         let name = &f.ident;
         quote! { #name }
@@ -121,6 +133,64 @@ pub fn derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let masked = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if let Some(#name) = &self.#name {
+                    if #ty::word() == 0 && #ty::mask() & mask > 0 {
+                        conditions.push(#name);
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
+    let guards = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if let Some(#name) = &self.#name {
+                    guards.push((GuardType::#ty, #ty::enable(Features::default()), #name));
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
+    let word_mask = fields.iter().map(|f| {
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if #ty::word() == word {
+                    mask |= #ty::mask();
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
+    let accounts_mask = fields.iter().map(|f| {
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if #ty::word() == word && #ty::uses_accounts() {
+                    mask |= #ty::mask();
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
     let struct_size = fields.iter().map(|f| {
         let name = &f.ident;
 
@@ -136,12 +206,27 @@ pub fn derive(input: TokenStream) -> TokenStream {
         }
     });
 
+    let features = fields.iter().map(|f| {
+        let name = &f.ident;
+
+        if is_option_t(&f.ty) {
+            let ty = unwrap_option_t(&f.ty);
+            quote! {
+                if self.#name.is_some() {
+                    features = #ty::enable(features);
+                }
+            }
+        } else {
+            quote! {}
+        }
+    });
+
     let bytes_count = fields.iter().map(|f| {
         if is_option_t(&f.ty) {
             let ty = unwrap_option_t(&f.ty);
             quote! {
                 if #ty::is_enabled(features) {
-                    count += #ty::size();
+                    count += #ty::size_for(version);
                 }
             }
         } else {
@@ -171,11 +256,10 @@ pub fn derive(input: TokenStream) -> TokenStream {
 
     let expanded = quote! {
         impl #name {
-            pub fn from_data(data: &[u8]) -> anchor_lang::Result<(Self, u64)> {
-                let mut cursor = 0;
-
-                let features = u64::from_le_bytes(*arrayref::array_ref![data, cursor, 8]);
-                cursor += 8;
+            pub fn from_data(data: &[u8], version: u8) -> anchor_lang::Result<(Self, Features)> {
+                // guards are read using their size on the layout version of the data
+                let features = Features::from_data(data)?;
+                let mut cursor = features.size();
 
                 #(#from_data)*
 
@@ -184,29 +268,43 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 }, features))
             }
 
-            pub fn bytes_count(features: u64) -> usize {
-                let mut count = 8; // features (u64)
+            pub fn bytes_count(features: &Features, version: u8) -> usize {
+                let mut count = features.size();
                 #(#bytes_count)*
                 count
             }
 
-            pub fn to_data(&self, data: &mut [u8]) -> anchor_lang::Result<u64> {
-                let mut features = 0;
-                // leave space to write the features flag at the end
-                let mut cursor = 8;
+            pub fn to_data(&self, data: &mut [u8]) -> anchor_lang::Result<Features> {
+                let features = self.features();
+                // leave space to write the features bitmap
+                let mut cursor = features.size();
 
                 #(#to_data)*
 
                 // features
-                data[0..8].copy_from_slice(&u64::to_le_bytes(features));
+                features.to_data(data)?;
 
                 Ok(features)
             }
 
+            pub fn features(&self) -> Features {
+                let mut features = Features::default();
+                #(#features)*
+                features
+            }
+
             pub fn merge(&mut self, other: GuardSet) {
                 #(#merge_data)*
             }
 
+            pub fn set_guard(&mut self, guard: &GuardType, other: &GuardSet) {
+                // replaces the guard with the one of the other guard set (a guard
+                // not enabled on the other guard set is cleared)
+                match guard {
+                    #(#set_arm,)*
+                }
+            }
+
             pub fn enabled_conditions(&self) -> Vec<&dyn Condition> {
                 // list of condition trait objects
                 let mut conditions: Vec<&dyn Condition> = vec![];
@@ -215,8 +313,40 @@ pub fn derive(input: TokenStream) -> TokenStream {
                 conditions
             }
 
+            pub fn conditions(&self, mask: u64) -> Vec<&dyn Condition> {
+                // list of condition trait objects of the guards in the mask (only
+                // guards on the first word of the feature bitmap can be masked)
+                let mut conditions: Vec<&dyn Condition> = vec![];
+                #(#masked)*
+
+                conditions
+            }
+
+            pub fn word_mask(word: usize) -> u64 {
+                // feature mask of the guards on the specified word of the bitmap
+                let mut mask = 0;
+                #(#word_mask)*
+                mask
+            }
+
+            pub fn accounts_mask(word: usize) -> u64 {
+                // feature mask of the guards on the specified word of the bitmap
+                // that use remaining accounts
+                let mut mask = 0;
+                #(#accounts_mask)*
+                mask
+            }
+
+            pub fn enabled_guards(&self) -> Vec<(GuardType, Features, &dyn Condition)> {
+                // list of enabled guards with their type and feature flag
+                let mut guards: Vec<(GuardType, Features, &dyn Condition)> = vec![];
+                #(#guards)*
+
+                guards
+            }
+
             pub fn size(&self) -> usize {
-                let mut size = 8; // features (u64)
+                let mut size = self.features().size();
                 #(#struct_size)*
                 size
             }
@@ -86,4 +86,108 @@ pub enum CandyGuardError {
     // authority only
     #[msg("Address not authorized")]
     AddressNotAuthorized,
+    // freeze sol payment
+    #[msg("Missing freeze instruction data")]
+    MissingFreezeInstruction,
+    #[msg("Freeze guard must be enabled")]
+    FreezeGuardNotEnabled,
+    #[msg("Freeze must be initialized")]
+    FreezeNotInitialized,
+    #[msg("Missing freeze period")]
+    MissingFreezePeriod,
+    #[msg("The freeze escrow account already exists")]
+    FreezeEscrowAlreadyExists,
+    #[msg("Maximum freeze period exceeded")]
+    ExceededMaximumFreezePeriod,
+    #[msg("Thaw is not enabled")]
+    ThawNotEnabled,
+    #[msg("Unlock is not enabled (not all NFTs are thawed)")]
+    UnlockNotEnabled,
+    #[msg("Freeze sol payment and freeze token payment guards cannot be enabled together")]
+    IncompatibleFreezeGuards,
+    // program gate
+    #[msg("Maximum number of additional programs exceeded")]
+    ExceededProgramListSize,
+    #[msg("Transaction had an instruction from an unauthorized program")]
+    UnauthorizedProgramFound,
+    // allocation
+    #[msg("Missing allocation instruction data")]
+    MissingAllocationInstruction,
+    #[msg("Allocation guard must be enabled")]
+    AllocationNotEnabled,
+    #[msg("Allocation tracker is already initialized")]
+    AllocationTrackerAlreadyInitialized,
+    #[msg("Allocation tracker must be initialized")]
+    AllocationTrackerNotInitialized,
+    #[msg("The maximum number of mints of the allocation was reached")]
+    AllocationLimitReached,
+    // sol payment split
+    #[msg("Invalid number of destinations")]
+    InvalidNumberOfDestinations,
+    #[msg("The basis points of the destinations must add up to 10000")]
+    InvalidSplitBasisPoints,
+    // dutch auction
+    #[msg("Invalid dutch auction configuration")]
+    InvalidDutchAuction,
+    // bonding curve
+    #[msg("Invalid bonding curve configuration")]
+    InvalidBondingCurve,
+    // signed voucher
+    #[msg("Missing voucher signature instruction")]
+    MissingVoucherSignature,
+    #[msg("Voucher expired")]
+    VoucherExpired,
+    #[msg("Voucher has already been used")]
+    VoucherAlreadyUsed,
+    // allow list quota
+    #[msg("The mint allowance of the address was reached")]
+    AllowedListQuotaReached,
+    // allow list proof
+    #[msg("Missing allow list instruction data")]
+    MissingAllowListInstruction,
+    #[msg("Allow list proof expired")]
+    AllowedListProofExpired,
+    #[msg("Allow list proof has been revoked")]
+    AllowedListProofRevoked,
+    // combinator
+    #[msg("Invalid number of combinator branches")]
+    InvalidNumberOfBranches,
+    #[msg("Invalid combinator configuration")]
+    InvalidCombinator,
+    #[msg("A negated guard of the combinator succeeded")]
+    NegatedGuardSucceeded,
+    #[msg("None of the combinator branches succeeded")]
+    CombinatorConditionNotMet,
+    // group selection
+    #[msg("None of the groups is eligible for the mint")]
+    NoEligibleGroup,
+    // layout version
+    #[msg("Unsupported layout version of the candy guard data")]
+    UnsupportedLayoutVersion,
+    #[msg("Candy guard data must be migrated to the current layout version")]
+    MigrationRequired,
+    // authority transfer
+    #[msg("Signer is not the pending authority of the candy guard")]
+    InvalidPendingAuthority,
+    // delegates
+    #[msg("Signer is not the authority or a delegate of the candy guard")]
+    InvalidDelegate,
+    #[msg("Delegate role does not allow the operation")]
+    DelegateRoleNotAllowed,
+    // mint limit
+    #[msg("Mint limit guard must be enabled")]
+    MintLimitNotEnabled,
+    // granular updates
+    #[msg("Group label already in use")]
+    DuplicateGroupLabel,
+    // program guard
+    #[msg("Exceeded the maximum size of the program guard configuration")]
+    ExceededProgramGuardConfigSize,
+    #[msg("External program rejected the mint")]
+    ProgramGuardRejected,
+    // multiple mints
+    #[msg("Invalid number of items to mint")]
+    InvalidQuantity,
+    #[msg("A guard does not support minting multiple items in the same transaction")]
+    QuantityNotSupported,
 }
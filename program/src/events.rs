@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{DelegateRole, GuardType};
+
+/// Amount charged by a guard on a mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GuardCharge {
+    /// Type of the guard.
+    pub guard: GuardType,
+    /// Mint of the token charged (`None` for lamports).
+    pub mint: Option<Pubkey>,
+    /// Amount charged (lamports or token amount).
+    pub amount: u64,
+}
+
+/// Emitted on a successful mint.
+#[event]
+pub struct MintEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub payer: Pubkey,
+    /// Label of the group used to mint (`None` for the default guard set).
+    pub label: Option<String>,
+    /// Number of items minted.
+    pub quantity: u8,
+    /// Amounts charged by the guards.
+    pub charges: Vec<GuardCharge>,
+}
+
+/// Emitted when a transaction is charged by the bot tax.
+#[event]
+pub struct BotTaxEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+    pub payer: Pubkey,
+    /// Lamports charged.
+    pub lamports: u64,
+    /// Error code (program error value) of the failed validation.
+    pub error_code: u64,
+}
+
+/// Emitted when the candy guard configuration is updated.
+#[event]
+pub struct UpdateEvent {
+    pub candy_guard: Pubkey,
+    pub authority: Pubkey,
+    /// Size of the account after the update.
+    pub size: u64,
+}
+
+/// Emitted when a candy guard is added to a candy machine.
+#[event]
+pub struct WrapEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+}
+
+/// Emitted when a candy guard is removed from a candy machine.
+#[event]
+pub struct UnwrapEvent {
+    pub candy_guard: Pubkey,
+    pub candy_machine: Pubkey,
+}
+
+/// Emitted when a new authority is staged (or the pending authority is cleared).
+#[event]
+pub struct SetAuthorityEvent {
+    pub candy_guard: Pubkey,
+    pub authority: Pubkey,
+    /// Authority staged to replace the current authority.
+    pub pending_authority: Option<Pubkey>,
+}
+
+/// Emitted when the pending authority accepts the authority of a candy guard.
+#[event]
+pub struct AcceptAuthorityEvent {
+    pub candy_guard: Pubkey,
+    pub previous_authority: Pubkey,
+    pub authority: Pubkey,
+}
+
+/// Emitted when a role is granted to a delegate.
+#[event]
+pub struct CreateDelegateEvent {
+    pub candy_guard: Pubkey,
+    pub delegate: Pubkey,
+    pub role: DelegateRole,
+}
+
+/// Emitted when the role granted to a delegate is revoked.
+#[event]
+pub struct RevokeDelegateEvent {
+    pub candy_guard: Pubkey,
+    pub delegate: Pubkey,
+    pub role: DelegateRole,
+}
+
+/// Emitted when a candy guard account is closed.
+#[event]
+pub struct WithdrawEvent {
+    pub candy_guard: Pubkey,
+    pub authority: Pubkey,
+    /// Rent lamports withdrawn.
+    pub lamports: u64,
+}
@@ -0,0 +1,231 @@
+use solana_program::{program::invoke_signed, system_instruction, system_program};
+
+use super::*;
+use crate::{
+    instructions::Route,
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
+};
+
+/// Guard to specify the maximum number of mints in a guard set.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Allocation tracker PDA (seeds `["allocation", allocation id,
+///                   candy guard pubkey, candy machine pubkey]`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Allocation {
+    /// Unique identifier of the allocation.
+    pub id: u8,
+    /// The size of the allocation.
+    pub limit: u32,
+}
+
+/// PDA to track the number of mints.
+#[account]
+#[derive(Default)]
+pub struct AllocationTracker {
+    pub count: u32,
+}
+
+/// Instructions available for the allocation guard.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AllocationInstruction {
+    Initialize,
+    Reset,
+}
+
+impl Allocation {
+    /// Derives the allocation tracker PDA.
+    pub fn find_tracker_pda(&self, candy_guard: &Pubkey, candy_machine: &Pubkey) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"allocation".as_ref(),
+                &[self.id],
+                candy_guard.as_ref(),
+                candy_machine.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
+
+impl Guard for Allocation {
+    fn size() -> usize {
+        1   // id
+        + 4 // limit
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 19
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    /// Instructions to manage the allocation tracker. The first byte of the data
+    /// specifies the instruction:
+    ///
+    ///  * initialize (creates the tracker PDA)
+    ///  * reset (sets the tracker count back to zero)
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Allocation tracker PDA (seeds `["allocation", allocation id,
+    ///                   candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer]` Candy Guard authority.
+    ///   2. `[]` System program account (only for the initialize instruction).
+    fn instruction<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        guard_set: &GuardSet,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let allocation = if let Some(allocation) = &guard_set.allocation {
+            allocation
+        } else {
+            return err!(CandyGuardError::AllocationNotEnabled);
+        };
+
+        let instruction = if data.is_empty() {
+            return err!(CandyGuardError::MissingAllocationInstruction);
+        } else {
+            AllocationInstruction::try_from_slice(&data[0..1])
+                .map_err(|_| error!(CandyGuardError::MissingAllocationInstruction))?
+        };
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let allocation_pda = Self::get_account_info(ctx, 0)?;
+        let (pda, bump) = allocation.find_tracker_pda(candy_guard_key, candy_machine_key);
+        assert_keys_equal(allocation_pda.key, &pda)?;
+
+        let authority = Self::get_account_info(ctx, 1)?;
+
+        if !(cmp_pubkeys(authority.key, &ctx.accounts.candy_guard.authority) && authority.is_signer)
+        {
+            return err!(CandyGuardError::MissingRequiredSignature);
+        }
+
+        match instruction {
+            AllocationInstruction::Initialize => {
+                msg!("Instruction: Initialize (Allocation guard)");
+
+                if !allocation_pda.data_is_empty() {
+                    return err!(CandyGuardError::AllocationTrackerAlreadyInitialized);
+                }
+
+                let system_program = Self::get_account_info(ctx, 2)?;
+                assert_keys_equal(system_program.key, &system_program::ID)?;
+
+                let signer = [
+                    b"allocation".as_ref(),
+                    &[allocation.id],
+                    candy_guard_key.as_ref(),
+                    candy_machine_key.as_ref(),
+                    &[bump],
+                ];
+                let rent = Rent::get()?;
+
+                invoke_signed(
+                    &system_instruction::create_account(
+                        &ctx.accounts.payer.key(),
+                        &pda,
+                        rent.minimum_balance(std::mem::size_of::<u32>()),
+                        std::mem::size_of::<u32>() as u64,
+                        &crate::ID,
+                    ),
+                    &[
+                        ctx.accounts.payer.to_account_info(),
+                        allocation_pda.to_account_info(),
+                        system_program.to_account_info(),
+                    ],
+                    &[&signer],
+                )?;
+            }
+            AllocationInstruction::Reset => {
+                msg!("Instruction: Reset (Allocation guard)");
+
+                if allocation_pda.data_is_empty() {
+                    return err!(CandyGuardError::AllocationTrackerNotInitialized);
+                }
+
+                assert_owned_by(allocation_pda, &crate::ID)?;
+            }
+        }
+
+        // (re)sets the tracker count
+        let mut account_data = allocation_pda.try_borrow_mut_data()?;
+        let data = &mut AllocationTracker { count: 0 }.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
+
+impl Condition for Allocation {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let allocation = Self::get_account_info(ctx, evaluation_context.account_cursor)?;
+        evaluation_context
+            .indices
+            .insert("allocation_index", evaluation_context.account_cursor);
+        evaluation_context.account_cursor += 1;
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let (pda, _) = self.find_tracker_pda(candy_guard_key, candy_machine_key);
+        assert_keys_equal(allocation.key, &pda)?;
+
+        if allocation.data_is_empty() {
+            // sanity check: allocation tracker must exist
+            return err!(CandyGuardError::AllocationTrackerNotInitialized);
+        }
+
+        assert_owned_by(allocation, &crate::ID)?;
+
+        let account_data = allocation.data.borrow();
+        let allocation_tracker = AllocationTracker::try_from_slice(&account_data)?;
+
+        let quantity = evaluation_context.quantity as u64;
+
+        if allocation_tracker.count as u64 + quantity > self.limit as u64 {
+            return err!(CandyGuardError::AllocationLimitReached);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let allocation =
+            Self::get_account_info(ctx, evaluation_context.indices["allocation_index"])?;
+
+        let mut account_data = allocation.try_borrow_mut_data()?;
+        let mut allocation_tracker = AllocationTracker::try_from_slice(&account_data)?;
+        allocation_tracker.count = allocation_tracker
+            .count
+            .checked_add(evaluation_context.quantity as u32)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+        // saves the changes back to the pda
+        let data = &mut allocation_tracker.try_to_vec()?;
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+}
@@ -1,25 +1,47 @@
-use solana_program::{program::invoke_signed, system_instruction};
+use solana_program::system_program;
 
 use crate::{
     instructions::Route,
-    utils::{assert_keys_equal, assert_owned_by},
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys, create_or_allocate_account},
 };
 
 use super::*;
 
 /// Guard that uses a merkle tree to specify the addresses allowed to mint.
 ///
+/// The leaves of the merkle tree can optionally commit to a mint allowance for
+/// each address (quota mode), in which case the number of mints of the address
+/// is tracked by the proof PDA (the PDA must be writable in quota mode).
+///
+/// When a `proof_ttl` is specified, the proof expires after the specified number
+/// of seconds and the address needs to validate the proof again.
+///
 /// List of accounts required:
 ///
-///   0. `[]` Pda created by the merkle proof instruction (seeds `["allow_list", merke tree root, 
-///           payer key, candy guard pubkey, candy machine pubkey]`).
+///   0. `[writable]` Pda created by the merkle proof instruction (seeds `["allow_list", merke tree
+///                   root, payer key, candy guard pubkey, candy machine pubkey]`). The PDA only
+///                   needs to be writable when the leaves of the merkle tree commit to a quota.
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct AllowList {
     /// Merkle root of the addresses allowed to mint.
     pub merkle_root: [u8; 32],
+    /// Time (in seconds) that a proof remains valid after being validated.
+    pub proof_ttl: Option<i64>,
+}
+
+/// Instructions available for the allow list guard.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum AllowListInstruction {
+    Proof,
+    Revoke,
+    Close,
 }
 
 impl AllowList {
+    /// Size of the guard on accounts created before the layout was versioned, which
+    /// did not have a proof ttl.
+    pub const LEGACY_SIZE: usize = 32; // merkle_root
+
     /// Returns true if a `leaf` can be proved to be a part of a Merkle tree
     /// defined by `root`. For this, a `proof` must be provided, containing
     /// sibling hashes on the branch from the leaf to the root of the tree. Each
@@ -38,111 +60,410 @@ impl AllowList {
         // check if the computed hash (root) is equal to the provided root
         computed_hash == *root
     }
+
+    /// Returns the leaf of the merkle tree for the `user` address. When an `allowance`
+    /// is specified, the leaf also commits to the allowance of the address.
+    pub fn leaf(user: &Pubkey, allowance: Option<u32>) -> [u8; 32] {
+        if let Some(allowance) = allowance {
+            solana_program::keccak::hashv(&[user.to_string().as_bytes(), &allowance.to_le_bytes()])
+                .0
+        } else {
+            solana_program::keccak::hashv(&[user.to_string().as_bytes()]).0
+        }
+    }
 }
 
 impl Guard for AllowList {
     fn size() -> usize {
-        32 // merkle_root
+        32      // merkle_root
+        + 1 + 8 // option + proof ttl
     }
 
     fn mask() -> u64 {
         0b1u64 << 8
     }
 
-    /// Instruction to validate an address against the merkle tree.
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    /// Accounts created before the layout was versioned (version `0`) store the
+    /// merkle root only.
+    fn size_for(version: u8) -> usize {
+        if version == 0 {
+            Self::LEGACY_SIZE
+        } else {
+            Self::size()
+        }
+    }
+
+    fn load_for(data: &[u8], offset: usize, version: u8) -> Result<Option<Self>> {
+        if version > 0 {
+            return Self::load(data, offset);
+        }
+
+        if offset <= data.len() {
+            Ok(Some(Self {
+                merkle_root: *arrayref::array_ref![data, offset - Self::LEGACY_SIZE, 32],
+                proof_ttl: None,
+            }))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Instructions to manage the merkle proof PDAs. The first byte of the data
+    /// specifies the instruction:
     ///
-    /// List of accounts required:
+    ///  * proof (validates the payer address against the merkle tree)
+    ///  * revoke (revokes the proof of an address)
+    ///  * close (closes the proof PDA of an address)
     ///
-    ///   0. `[writable]` Pda to represent the merkle proof (seeds `["allow_list", merke tree root,
-    ///                   payer key, candy guard pubkey, candy machine pubkey]`).
-    ///   1. `[]` System program account.
+    /// Data without the instruction byte (only the merkle proof) is handled as a
+    /// proof instruction, which was the encoding used before the instruction byte.
     fn instruction<'info>(
         ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
         guard_set: &GuardSet,
         data: Vec<u8>,
     ) -> Result<()> {
-        msg!("AllowList: validate proof instruction");
-
-        // validates the proof
-
-        let merkle_proof: Vec<[u8; 32]> = if let Ok(proof) = Vec::try_from_slice(&data[..]) {
-            proof
+        let allow_list = if let Some(allow_list) = &guard_set.allow_list {
+            allow_list
         } else {
-            return err!(CandyGuardError::MissingAllowedListProof);
+            return err!(CandyGuardError::AllowedListNotEnabled);
         };
 
-        let user = ctx.accounts.payer.key();
-        let leaf = solana_program::keccak::hashv(&[user.to_string().as_bytes()]);
+        if Self::is_legacy_proof(&data) {
+            msg!("AllowList: validate proof instruction");
+            return allow_list.validate_proof(ctx, &data);
+        }
 
-        let merkle_root = if let Some(allow_list) = &guard_set.allow_list {
-            &allow_list.merkle_root
+        let instruction = if data.is_empty() {
+            return err!(CandyGuardError::MissingAllowListInstruction);
         } else {
-            return err!(CandyGuardError::AllowedListNotEnabled);
+            AllowListInstruction::try_from_slice(&data[0..1])
+                .map_err(|_| error!(CandyGuardError::MissingAllowListInstruction))?
         };
 
-        if !Self::verify(&merkle_proof[..], merkle_root, &leaf.0) {
-            return err!(CandyGuardError::AddressNotFoundInAllowedList);
+        match instruction {
+            // Validates the payer address against the merkle tree. The instruction
+            // data is the `Vec` of the merkle proof hash values, optionally followed
+            // by the mint allowance (`u32`) of the address when the leaves of the
+            // tree commit to a quota.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Pda to represent the merkle proof (seeds `["allow_list", merke tree root,
+            //                   payer key, candy guard pubkey, candy machine pubkey]`).
+            //   1. `[]` System program account.
+            AllowListInstruction::Proof => {
+                msg!("AllowList: validate proof instruction");
+                allow_list.validate_proof(ctx, &data[1..])
+            }
+            // Revokes the proof of an address, preventing it from being validated
+            // again under the same merkle root. The instruction data is the address
+            // (`Pubkey`) and the merkle root (`[u8; 32]`) of the proof.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Pda to represent the merkle proof (seeds `["allow_list", merke tree root,
+            //                   address, candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[]` System program account.
+            AllowListInstruction::Revoke => {
+                msg!("AllowList: revoke proof instruction");
+                Self::revoke_proof(ctx, &data[1..])
+            }
+            // Closes the proof PDA of an address, returning the rent lamports to the
+            // authority. The instruction data is the address (`Pubkey`) and the merkle
+            // root (`[u8; 32]`) of the proof.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Pda to represent the merkle proof (seeds `["allow_list", merke tree root,
+            //                   address, candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer, writable]` Candy Guard authority.
+            AllowListInstruction::Close => {
+                msg!("AllowList: close proof instruction");
+                Self::close_proof(ctx, &data[1..])
+            }
         }
+    }
+}
 
-        // creates the proof PDA
+impl AllowList {
+    /// Derives the proof PDA of an address.
+    pub fn find_proof_pda(
+        merkle_root: &[u8; 32],
+        user: &Pubkey,
+        candy_guard: &Pubkey,
+        candy_machine: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"allow_list",
+                &merkle_root[..],
+                user.as_ref(),
+                candy_guard.as_ref(),
+                candy_machine.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
 
+    /// Creates the proof PDA of an address.
+    fn create_proof_pda<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        proof_pda: &AccountInfo<'info>,
+        system_program: &AccountInfo<'info>,
+        merkle_root: &[u8; 32],
+        user: &Pubkey,
+        size: usize,
+    ) -> Result<()> {
         let candy_guard_key = &ctx.accounts.candy_guard.key();
         let candy_machine_key = &ctx.accounts.candy_machine.key();
 
-        let proof_pda = Self::get_account_info(ctx, 0)?;
-        let seeds = [
+        let (_, bump) = Self::find_proof_pda(merkle_root, user, candy_guard_key, candy_machine_key);
+
+        let signer = [
             b"allow_list",
             &merkle_root[..],
             user.as_ref(),
             candy_guard_key.as_ref(),
             candy_machine_key.as_ref(),
+            &[bump],
         ];
-        let (pda, bump) = Pubkey::find_program_address(&seeds, &crate::ID);
+
+        // the address of the PDA might have already been funded
+        create_or_allocate_account(
+            proof_pda,
+            &ctx.accounts.payer.to_account_info(),
+            system_program,
+            size,
+            &signer,
+        )
+    }
+
+    /// Validates the payer address against the merkle tree and creates (or updates)
+    /// the proof PDA.
+    fn validate_proof<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        data: &[u8],
+    ) -> Result<()> {
+        // validates the proof
+
+        let mut remaining = data;
+
+        let merkle_proof: Vec<[u8; 32]> = if let Ok(proof) = Vec::deserialize(&mut remaining) {
+            proof
+        } else {
+            return err!(CandyGuardError::MissingAllowedListProof);
+        };
+
+        let allowance = if remaining.is_empty() {
+            None
+        } else if let Ok(allowance) = u32::try_from_slice(remaining) {
+            Some(allowance)
+        } else {
+            return err!(CandyGuardError::MissingAllowedListProof);
+        };
+
+        let user = ctx.accounts.payer.key();
+        let leaf = Self::leaf(&user, allowance);
+        let merkle_root = &self.merkle_root;
+
+        if !Self::verify(&merkle_proof[..], merkle_root, &leaf) {
+            return err!(CandyGuardError::AddressNotFoundInAllowedList);
+        }
+
+        // creates the proof PDA
+
+        let proof_pda = Self::get_account_info(ctx, 0)?;
+        let (pda, _) = Self::find_proof_pda(
+            merkle_root,
+            &user,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        );
 
         assert_keys_equal(proof_pda.key, &pda)?;
 
+        let size = if allowance.is_some() {
+            AllowListProof::QUOTA_SIZE
+        } else {
+            AllowListProof::SIZE
+        };
+
         if proof_pda.data_is_empty() {
-            let signer = [
-                b"allow_list",
-                &merkle_root[..],
-                user.as_ref(),
-                candy_guard_key.as_ref(),
-                candy_machine_key.as_ref(),
-                &[bump],
-            ];
-            let rent = Rent::get()?;
-
-            invoke_signed(
-                &system_instruction::create_account(
-                    &ctx.accounts.payer.key(),
-                    &pda,
-                    rent.minimum_balance(std::mem::size_of::<i64>()),
-                    std::mem::size_of::<i64>() as u64,
-                    &crate::ID,
-                ),
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    proof_pda.to_account_info(),
-                ],
-                &[&signer],
-            )?;
+            let system_program = Self::get_account_info(ctx, 1)?;
+            assert_keys_equal(system_program.key, &system_program::ID)?;
+
+            Self::create_proof_pda(ctx, proof_pda, system_program, merkle_root, &user, size)?;
         } else {
             // if it an existing account, make sure it has the correct ownwer
             assert_owned_by(&proof_pda, &crate::ID)?;
+
+            if AllowListProof::load(proof_pda)?.is_revoked() {
+                return err!(CandyGuardError::AllowedListProofRevoked);
+            }
+
+            if proof_pda.data_len() != size {
+                return err!(CandyGuardError::InvalidAccountSize);
+            }
         }
 
         let mut account_data = proof_pda.try_borrow_mut_data()?;
-        let mut proof = AllowListProof::try_from_slice(&account_data)?;
+        let mut proof = AllowListProof::deserialize(&mut &account_data[..])?;
         proof.timestamp = Clock::get()?.unix_timestamp;
         // saves the changes back to the pda
         let data = &mut proof.try_to_vec().unwrap();
         account_data[0..data.len()].copy_from_slice(data);
 
+        if let Some(allowance) = allowance {
+            // the number of mints already consumed is kept when the proof is
+            // validated again
+            let mut quota = AllowListQuota::try_from_slice(&account_data[AllowListProof::SIZE..])?;
+            quota.allowance = allowance;
+            let data = &mut quota.try_to_vec().unwrap();
+            account_data[AllowListProof::SIZE..].copy_from_slice(data);
+        }
+
         Ok(())
     }
+
+    /// Revokes the proof of an address. The proof PDA is created if it does not exist,
+    /// so an address can be revoked before its proof is validated.
+    fn revoke_proof<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        data: &[u8],
+    ) -> Result<()> {
+        let (user, merkle_root) = Self::proof_args(data)?;
+        let proof_pda = Self::get_account_info(ctx, 0)?;
+        Self::assert_authority(ctx)?;
+
+        let (pda, _) = Self::find_proof_pda(
+            &merkle_root,
+            &user,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        );
+        assert_keys_equal(proof_pda.key, &pda)?;
+
+        if proof_pda.data_is_empty() {
+            let system_program = Self::get_account_info(ctx, 2)?;
+            assert_keys_equal(system_program.key, &system_program::ID)?;
+
+            Self::create_proof_pda(
+                ctx,
+                proof_pda,
+                system_program,
+                &merkle_root,
+                &user,
+                AllowListProof::SIZE,
+            )?;
+        } else {
+            assert_owned_by(proof_pda, &crate::ID)?;
+        }
+
+        let mut account_data = proof_pda.try_borrow_mut_data()?;
+        let proof = AllowListProof {
+            timestamp: AllowListProof::REVOKED,
+        };
+        // saves the changes back to the pda
+        let data = &mut proof.try_to_vec().unwrap();
+        account_data[0..data.len()].copy_from_slice(data);
+
+        Ok(())
+    }
+
+    /// Closes the proof PDA of an address.
+    fn close_proof<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        data: &[u8],
+    ) -> Result<()> {
+        let (user, merkle_root) = Self::proof_args(data)?;
+        let proof_pda = Self::get_account_info(ctx, 0)?;
+        let authority = Self::assert_authority(ctx)?;
+
+        let (pda, _) = Self::find_proof_pda(
+            &merkle_root,
+            &user,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        );
+        assert_keys_equal(proof_pda.key, &pda)?;
+        assert_owned_by(proof_pda, &crate::ID)?;
+
+        let lamports = proof_pda.lamports();
+
+        **authority.lamports.borrow_mut() = authority
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+        **proof_pda.lamports.borrow_mut() = 0;
+
+        let mut account_data = proof_pda.try_borrow_mut_data()?;
+        account_data.fill(0);
+
+        Ok(())
+    }
+
+    /// Returns whether the route data is a merkle proof without the instruction byte.
+    /// The data is a `Vec` of 32-byte hashes, so its length prefix matches the number
+    /// of bytes that follow it; this is never the case when the data starts with an
+    /// instruction byte (the length of the proof arguments is `1 + 4 + 32 * n` with an
+    /// optional `u32` allowance, and the revoke and close arguments use 65 bytes).
+    fn is_legacy_proof(data: &[u8]) -> bool {
+        if data.len() < 4 {
+            return false;
+        }
+
+        let length = u32::from_le_bytes(*arrayref::array_ref![data, 0, 4]) as usize;
+        length.checked_mul(32) == Some(data.len() - 4)
+    }
+
+    /// Asserts that the proof has not expired at the specified timestamp.
+    fn assert_not_expired(&self, proof: &AllowListProof, timestamp: i64) -> Result<()> {
+        if let Some(proof_ttl) = self.proof_ttl {
+            let expiry = proof
+                .timestamp
+                .checked_add(proof_ttl)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+            if timestamp > expiry {
+                return err!(CandyGuardError::AllowedListProofExpired);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reads the address and merkle root of a proof from the instruction data.
+    fn proof_args(data: &[u8]) -> Result<(Pubkey, [u8; 32])> {
+        <(Pubkey, [u8; 32])>::try_from_slice(data)
+            .map_err(|_| error!(CandyGuardError::MissingAllowListInstruction))
+    }
+
+    /// Asserts that the candy guard authority signed the transaction.
+    fn assert_authority<'c, 'info>(
+        ctx: &Context<'_, '_, 'c, 'info, Route<'info>>,
+    ) -> Result<&'c AccountInfo<'info>> {
+        let authority = Self::get_account_info(ctx, 1)?;
+
+        if !(cmp_pubkeys(authority.key, &ctx.accounts.candy_guard.authority) && authority.is_signer)
+        {
+            return err!(CandyGuardError::MissingRequiredSignature);
+        }
+
+        Ok(authority)
+    }
 }
 
 impl Condition for AllowList {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -151,6 +472,9 @@ impl Condition for AllowList {
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
         let proof_pda = Self::get_account_info(ctx, evaluation_context.account_cursor)?;
+        evaluation_context
+            .indices
+            .insert("allow_list_index", evaluation_context.account_cursor);
         evaluation_context.account_cursor += 1;
         let user = ctx.accounts.payer.key();
 
@@ -176,13 +500,157 @@ impl Condition for AllowList {
 
         assert_owned_by(proof_pda, &crate::ID)?;
 
+        let proof = AllowListProof::load(proof_pda)?;
+
+        if proof.is_revoked() {
+            return err!(CandyGuardError::AllowedListProofRevoked);
+        }
+
+        if self.proof_ttl.is_some() {
+            self.assert_not_expired(&proof, Clock::get()?.unix_timestamp)?;
+        }
+
+        if let Some(quota) = AllowListQuota::load(proof_pda)? {
+            if quota.consumed as u64 + evaluation_context.quantity as u64 > quota.allowance as u64 {
+                return err!(CandyGuardError::AllowedListQuotaReached);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let proof_pda =
+            Self::get_account_info(ctx, evaluation_context.indices["allow_list_index"])?;
+
+        if let Some(mut quota) = AllowListQuota::load(proof_pda)? {
+            quota.consumed = quota
+                .consumed
+                .checked_add(evaluation_context.quantity as u32)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+            // saves the changes back to the pda
+            let mut account_data = proof_pda.try_borrow_mut_data()?;
+            let data = &mut quota.try_to_vec().unwrap();
+            account_data[AllowListProof::SIZE..].copy_from_slice(data);
+        }
+
         Ok(())
     }
 }
 
-/// PDA to track whether an address has been validated or not.
+/// PDA to track whether an address has been validated or not. In quota mode, the
+/// proof is followed by the `AllowListQuota` of the address.
 #[account]
 #[derive(Default)]
 pub struct AllowListProof {
     pub timestamp: i64,
 }
+
+impl AllowListProof {
+    /// Size of the proof account.
+    pub const SIZE: usize = 8; // timestamp
+
+    /// Size of the proof account in quota mode.
+    pub const QUOTA_SIZE: usize = Self::SIZE
+        + 4  // allowance
+        + 4; // consumed
+
+    /// Timestamp value of a revoked proof.
+    pub const REVOKED: i64 = i64::MIN;
+
+    /// Loads the proof from a proof PDA.
+    pub fn load(proof_pda: &AccountInfo) -> Result<Self> {
+        let account_data = proof_pda.data.borrow();
+        Ok(Self::deserialize(&mut &account_data[..])?)
+    }
+
+    /// Returns whether the proof has been revoked or not.
+    pub fn is_revoked(&self) -> bool {
+        self.timestamp == Self::REVOKED
+    }
+}
+
+/// Mint allowance of an address and the number of mints consumed.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct AllowListQuota {
+    pub allowance: u32,
+    pub consumed: u32,
+}
+
+impl AllowListQuota {
+    /// Loads the quota from a proof PDA. Returns `None` if the proof was not
+    /// validated in quota mode.
+    pub fn load(proof_pda: &AccountInfo) -> Result<Option<Self>> {
+        let account_data = proof_pda.data.borrow();
+
+        if account_data.len() < AllowListProof::QUOTA_SIZE {
+            return Ok(None);
+        }
+
+        Ok(Some(Self::try_from_slice(
+            &account_data[AllowListProof::SIZE..AllowListProof::QUOTA_SIZE],
+        )?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_proof_data() {
+        // merkle proof without the instruction byte
+        let proof = vec![[1u8; 32], [2u8; 32]];
+        assert!(AllowList::is_legacy_proof(&proof.try_to_vec().unwrap()));
+        assert!(AllowList::is_legacy_proof(
+            &Vec::<[u8; 32]>::new().try_to_vec().unwrap()
+        ));
+
+        // instruction byte followed by the merkle proof (and allowance)
+        let mut data = AllowListInstruction::Proof.try_to_vec().unwrap();
+        data.extend_from_slice(&proof.try_to_vec().unwrap());
+        assert!(!AllowList::is_legacy_proof(&data));
+        data.extend_from_slice(&5u32.to_le_bytes());
+        assert!(!AllowList::is_legacy_proof(&data));
+
+        // revoke and close arguments
+        let mut data = AllowListInstruction::Revoke.try_to_vec().unwrap();
+        data.extend_from_slice(&(Pubkey::new_unique(), [3u8; 32]).try_to_vec().unwrap());
+        assert!(!AllowList::is_legacy_proof(&data));
+
+        assert!(!AllowList::is_legacy_proof(&[]));
+        assert!(!AllowList::is_legacy_proof(&[0, 0, 0]));
+    }
+
+    #[test]
+    fn proof_expiry() {
+        let proof = AllowListProof { timestamp: 1_000 };
+        let mut guard = AllowList {
+            merkle_root: [0; 32],
+            proof_ttl: None,
+        };
+
+        // proofs do not expire without a ttl
+        assert!(guard.assert_not_expired(&proof, i64::MAX).is_ok());
+
+        guard.proof_ttl = Some(60);
+        assert!(guard.assert_not_expired(&proof, 1_000).is_ok());
+        assert!(guard.assert_not_expired(&proof, 1_060).is_ok());
+        assert_eq!(
+            guard.assert_not_expired(&proof, 1_061).unwrap_err(),
+            error!(CandyGuardError::AllowedListProofExpired)
+        );
+
+        guard.proof_ttl = Some(i64::MAX);
+        assert_eq!(
+            guard.assert_not_expired(&proof, 1_061).unwrap_err(),
+            error!(CandyGuardError::NumericalOverflowError)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use super::*;
+
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{
+    errors::CandyGuardError,
+    utils::{
+        assert_is_ata, assert_is_mint, assert_keys_equal, assert_token_program, get_pre_fee_amount,
+        spl_token_transfer, TokenTransferParams,
+    },
+};
+
+/// Guard that charges a price for the mint that increases as items are redeemed
+/// from the candy machine. The price is paid in SOL (lamports) or, when a token
+/// mint is specified, in spl-token amount.
+///
+/// The price of a mint is `base_price + increment * (items_redeemed / step_size)`,
+/// e.g., a `step_size` of `1` increases the price linearly at every mint, while a
+/// larger value defines tiers of `step_size` items with the same price.
+///
+/// List of accounts required (SOL payment):
+///
+///   0. `[writable]` Account to receive the funds.
+///
+/// List of accounts required (token payment):
+///
+///   0. `[writable]` Token account holding the required amount.
+///   1. `[writable]` Address of the ATA to receive the tokens.
+///   2. `[]` Token mint account.
+///   3. `[]` Token program of the mint (spl-token or Token-2022).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BondingCurve {
+    /// Price of the first item.
+    pub base_price: u64,
+    /// Amount added to the price at every step.
+    pub increment: u64,
+    /// Number of items redeemed per price step.
+    pub step_size: u64,
+    /// Mint of the token used as payment (`None` for payment in SOL).
+    pub token_mint: Option<Pubkey>,
+    /// Address to receive the funds (address of the ATA for token payments).
+    pub destination: Pubkey,
+}
+
+impl BondingCurve {
+    /// Returns the price of a mint when `items_redeemed` items have been redeemed.
+    pub fn price_at(&self, items_redeemed: u64) -> Result<u64> {
+        let steps = items_redeemed
+            .checked_div(self.step_size)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        self.increment
+            .checked_mul(steps)
+            .and_then(|increase| increase.checked_add(self.base_price))
+            .ok_or_else(|| CandyGuardError::NumericalOverflowError.into())
+    }
+
+    /// Returns the total price of minting `quantity` items when `items_redeemed` items
+    /// have been redeemed, where each item is charged the price of its position.
+    pub fn total_price(&self, items_redeemed: u64, quantity: u8) -> Result<u64> {
+        (0..quantity as u64).try_fold(0u64, |total, offset| {
+            let price = self.price_at(items_redeemed + offset)?;
+
+            total
+                .checked_add(price)
+                .ok_or_else(|| CandyGuardError::NumericalOverflowError.into())
+        })
+    }
+}
+
+impl Guard for BondingCurve {
+    fn size() -> usize {
+        8        // base price
+        + 8      // increment
+        + 8      // step size
+        + 1 + 32 // option + token mint
+        + 32 // destination
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 22
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.step_size == 0 {
+            return err!(CandyGuardError::InvalidBondingCurve);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for BondingCurve {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(if self.token_mint.is_some() { 4 } else { 1 })
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        let price = self.total_price(
+            ctx.accounts.candy_machine.items_redeemed,
+            evaluation_context.quantity,
+        )?;
+
+        if let Some(token_mint) = &self.token_mint {
+            // validates that we received all required accounts
+            let token_account_info = Self::get_account_info(ctx, index)?;
+            let destination_ata = Self::get_account_info(ctx, index + 1)?;
+            let mint = Self::get_account_info(ctx, index + 2)?;
+            let token_program = Self::get_account_info(ctx, index + 3)?;
+            evaluation_context.account_cursor += 4;
+
+            assert_keys_equal(destination_ata.key, &self.destination)?;
+            assert_keys_equal(mint.key, token_mint)?;
+            assert_is_mint(mint)?;
+            assert_token_program(token_program, mint)?;
+
+            let token_account =
+                assert_is_ata(token_account_info, &ctx.accounts.payer.key(), token_mint)?;
+
+            if token_account.amount < get_pre_fee_amount(mint, price)? {
+                return err!(CandyGuardError::NotEnoughTokens);
+            }
+        } else {
+            // validates that we received all required accounts
+            let destination = Self::get_account_info(ctx, index)?;
+            evaluation_context.account_cursor += 1;
+            // validates the account information
+            assert_keys_equal(destination.key, &self.destination)?;
+
+            if ctx.accounts.payer.lamports() < price {
+                msg!(
+                    "Require {} lamports, accounts has {} lamports",
+                    price,
+                    ctx.accounts.payer.lamports(),
+                );
+                return err!(CandyGuardError::NotEnoughSOL);
+            }
+        }
+
+        evaluation_context
+            .indices
+            .insert("bonding_curve_index", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["bonding_curve_index"];
+        // the price is computed before the mint, so it does not include the
+        // items being minted
+        let price = self.total_price(
+            ctx.accounts.candy_machine.items_redeemed,
+            evaluation_context.quantity,
+        )?;
+
+        // amount transferred, including the transfer fee of the mint (if any)
+        let amount = if self.token_mint.is_some() {
+            // the accounts have already been validated
+            let token_account_info = Self::get_account_info(ctx, index)?;
+            let destination_ata = Self::get_account_info(ctx, index + 1)?;
+            let mint = Self::get_account_info(ctx, index + 2)?;
+            let token_program = Self::get_account_info(ctx, index + 3)?;
+
+            let decimals = assert_is_mint(mint)?.decimals;
+            let amount = get_pre_fee_amount(mint, price)?;
+
+            spl_token_transfer(TokenTransferParams {
+                source: token_account_info.to_account_info(),
+                destination: destination_ata.to_account_info(),
+                mint: mint.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+                authority_signer_seeds: &[],
+                token_program: token_program.to_account_info(),
+                amount,
+                decimals,
+            })?;
+
+            amount
+        } else {
+            let destination = Self::get_account_info(ctx, index)?;
+
+            invoke(
+                &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), price),
+                &[
+                    ctx.accounts.payer.to_account_info(),
+                    destination.to_account_info(),
+                    ctx.accounts.system_program.to_account_info(),
+                ],
+            )?;
+
+            price
+        };
+
+        evaluation_context.charge(GuardType::BondingCurve, self.token_mint, amount);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve(step_size: u64) -> BondingCurve {
+        BondingCurve {
+            base_price: 1_000,
+            increment: 100,
+            step_size,
+            token_mint: None,
+            destination: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn price_increases_with_items_redeemed() {
+        let guard = curve(1);
+        assert_eq!(guard.price_at(0).unwrap(), 1_000);
+        assert_eq!(guard.price_at(1).unwrap(), 1_100);
+        assert_eq!(guard.price_at(10).unwrap(), 2_000);
+
+        // tiers of 5 items with the same price
+        let guard = curve(5);
+        assert_eq!(guard.price_at(4).unwrap(), 1_000);
+        assert_eq!(guard.price_at(5).unwrap(), 1_100);
+        assert_eq!(guard.price_at(12).unwrap(), 1_200);
+
+        let mut guard = curve(1);
+        guard.increment = u64::MAX;
+        assert!(guard.price_at(2).is_err());
+    }
+
+    #[test]
+    fn total_price_of_multiple_items() {
+        let guard = curve(1);
+        assert_eq!(guard.total_price(0, 1).unwrap(), 1_000);
+        assert_eq!(guard.total_price(0, 3).unwrap(), 1_000 + 1_100 + 1_200);
+        assert_eq!(guard.total_price(10, 2).unwrap(), 2_000 + 2_100);
+
+        // items crossing a price tier
+        let guard = curve(5);
+        assert_eq!(guard.total_price(3, 4).unwrap(), 1_000 * 2 + 1_100 * 2);
+
+        let mut guard = curve(1);
+        guard.base_price = u64::MAX;
+        assert_eq!(guard.total_price(0, 1).unwrap(), u64::MAX);
+        assert!(guard.total_price(0, 2).is_err());
+    }
+}
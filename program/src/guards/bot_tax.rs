@@ -1,12 +1,9 @@
 use solana_program::{
-    program::invoke,
-    serialize_utils::{read_pubkey, read_u16},
-    system_instruction,
-    sysvar::instructions::get_instruction_relative,
+    program::invoke, system_instruction, sysvar::instructions::get_instruction_relative,
 };
 
-use super::*;
-use crate::{errors::CandyGuardError, utils::cmp_pubkeys};
+use super::{program_gate::DEFAULT_PROGRAMS, *};
+use crate::{errors::CandyGuardError, events::BotTaxEvent, utils::cmp_pubkeys};
 
 /// Guard is used to:
 /// * charge a penalty for invalid transactions.
@@ -35,13 +32,12 @@ impl Condition for BotTax {
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
         _mint_args: &[u8],
-        _guard_set: &GuardSet,
+        guard_set: &GuardSet,
         _evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
         if self.last_instruction {
             let instruction_sysvar_account = &ctx.accounts.instruction_sysvar_account;
             let instruction_sysvar_account_info = instruction_sysvar_account.to_account_info();
-            let instruction_sysvar = instruction_sysvar_account_info.data.borrow();
             // the next instruction after the mint
             let next_ix = get_instruction_relative(1, &instruction_sysvar_account_info);
 
@@ -61,30 +57,11 @@ impl Condition for BotTax {
                 }
             }
 
-            let mut idx = 0;
-            let num_instructions = read_u16(&mut idx, &instruction_sysvar)
-                .map_err(|_| ProgramError::InvalidAccountData)?;
-
-            for index in 0..num_instructions {
-                let mut current = 2 + (index * 2) as usize;
-                let start = read_u16(&mut current, &instruction_sysvar).unwrap();
-
-                current = start as usize;
-                let num_accounts = read_u16(&mut current, &instruction_sysvar).unwrap();
-                current += (num_accounts as usize) * (1 + 32);
-                let program_id = read_pubkey(&mut current, &instruction_sysvar).unwrap();
-
-                if !cmp_pubkeys(&program_id, &crate::id())
-                    && !cmp_pubkeys(&program_id, &::spl_token::id())
-                    && !cmp_pubkeys(
-                        &program_id,
-                        &anchor_lang::solana_program::system_program::ID,
-                    )
-                    && !cmp_pubkeys(&program_id, &spl_associated_token_account::ID)
-                {
-                    msg!("Transaction had ix with program id {}", program_id);
-                    return err!(CandyGuardError::MintNotLastTransaction);
-                }
+            // when the program gate guard is enabled, it is responsible for validating
+            // the programs of the transaction
+            if guard_set.program_gate.is_none() {
+                ProgramGate::verify(&instruction_sysvar_account_info, DEFAULT_PROGRAMS)
+                    .map_err(|_| error!(CandyGuardError::MintNotLastTransaction))?;
             }
         }
 
@@ -114,6 +91,14 @@ impl BotTax {
             &[bot_account, payment_account, system_program],
         )?;
 
+        emit!(BotTaxEvent {
+            candy_guard: ctx.accounts.candy_guard.key(),
+            candy_machine: ctx.accounts.candy_machine.key(),
+            payer: ctx.accounts.payer.key(),
+            lamports: final_fee,
+            error_code: u64::from(ProgramError::from(error)),
+        });
+
         Ok(())
     }
 }
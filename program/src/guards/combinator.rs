@@ -0,0 +1,278 @@
+use super::*;
+
+/// Maximum number of branches.
+pub const MAXIMUM_BRANCHES: usize = 4;
+
+/// Guard that combines other guards of the same guard set using boolean logic.
+/// The guards combined are not evaluated individually; instead, the combinator
+/// succeeds when:
+///
+/// * all guards of at least one of the `branches` succeed (any-of / all-of); and
+/// * none of the `negated` guards succeed (not).
+///
+/// Each branch and the negated list are feature masks of the guards (e.g.,
+/// `NftGate::mask() | TokenGate::mask()`), so only guards on the first word of
+/// the feature bitmap can be combined. Guards that use remaining accounts cannot be
+/// negated. Every guard combined must be enabled on the guard set. The branches are
+/// evaluated in order and the first branch to succeed is used to perform the actions
+/// of the guards.
+///
+/// List of accounts required:
+///
+///   0..n. Accounts required by the guards of the branch expected to succeed, in
+///         the same order as the guards of the guard set. Every branch is evaluated
+///         starting from the same account. Negated guards do not receive accounts.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct Combinator {
+    /// Feature masks of the guards of each branch.
+    pub branches: Vec<u64>,
+    /// Feature mask of the guards that must not succeed.
+    pub negated: u64,
+}
+
+impl Combinator {
+    /// Returns the feature mask of all guards combined.
+    pub fn members(&self) -> u64 {
+        self.branches
+            .iter()
+            .fold(self.negated, |members, branch| members | branch)
+    }
+
+    /// Validates that all guards combined are enabled on the guard set; a combined
+    /// guard that is not enabled would otherwise be skipped by the combinator and by
+    /// the mint.
+    pub fn assert_members(&self, guard_set: &GuardSet) -> Result<()> {
+        let members = self.members();
+
+        if guard_set.conditions(members).len() != members.count_ones() as usize {
+            msg!("Combinator guards must be enabled on the guard set");
+            return err!(CandyGuardError::InvalidCombinator);
+        }
+
+        Ok(())
+    }
+
+    /// Validates the guards of a branch. Returns the evaluation context of the
+    /// branch when all guards succeed.
+    fn validate_branch<'a, 'info>(
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        guard_set: &GuardSet,
+        evaluation_context: &EvaluationContext<'a>,
+        branch: u64,
+    ) -> Result<EvaluationContext<'a>> {
+        let mut branch_context = evaluation_context.clone();
+        let conditions = guard_set.conditions(branch);
+
+        // every guard of the branch must be enabled
+        if conditions.len() != branch.count_ones() as usize {
+            return err!(CandyGuardError::InvalidCombinator);
+        }
+
+        for condition in conditions {
+            condition.validate(ctx, mint_args, guard_set, &mut branch_context)?;
+        }
+
+        Ok(branch_context)
+    }
+}
+
+impl Guard for Combinator {
+    fn size() -> usize {
+        4 // u32 (number of branches)
+        + (MAXIMUM_BRANCHES * 8) // max list of branches
+        + 8 // negated
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 24
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.branches.is_empty() || self.branches.len() > MAXIMUM_BRANCHES {
+            return err!(CandyGuardError::InvalidNumberOfBranches);
+        }
+
+        // the combinator cannot combine itself and each branch must have at least
+        // one guard
+        if self.members() & Self::mask() > 0 || self.branches.contains(&0) {
+            return err!(CandyGuardError::InvalidCombinator);
+        }
+
+        // only guards on the first word of the feature bitmap can be combined
+        if self.members() & !GuardSet::word_mask(0) > 0 {
+            msg!("Combinator can only combine guards on the first word of the feature bitmap");
+            return err!(CandyGuardError::InvalidCombinator);
+        }
+
+        // negated guards are evaluated without remaining accounts, so they would
+        // never succeed (or fail) on their condition
+        if self.negated & GuardSet::accounts_mask(0) > 0 {
+            msg!("Combinator cannot negate guards that use remaining accounts");
+            return err!(CandyGuardError::InvalidCombinator);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for Combinator {
+    /// The accounts used depend on the branch that succeeds.
+    fn accounts_count(&self) -> Option<usize> {
+        None
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        // negated guards are evaluated without remaining accounts (guards that use
+        // remaining accounts cannot be negated)
+        if self.negated > 0 {
+            let mut negated_context = evaluation_context.clone();
+            negated_context.account_cursor = ctx.remaining_accounts.len();
+            let conditions = guard_set.conditions(self.negated);
+
+            // a negated guard that is not enabled would never succeed
+            if conditions.len() != self.negated.count_ones() as usize {
+                return err!(CandyGuardError::InvalidCombinator);
+            }
+
+            for condition in conditions {
+                match condition.validate(ctx, mint_args, guard_set, &mut negated_context) {
+                    Ok(()) => return err!(CandyGuardError::NegatedGuardSucceeded),
+                    Err(error) if error == error!(CandyGuardError::MissingRemainingAccount) => {
+                        return Err(error)
+                    }
+                    Err(_) => (),
+                }
+            }
+        }
+
+        for (index, branch) in self.branches.iter().enumerate() {
+            match Self::validate_branch(ctx, mint_args, guard_set, evaluation_context, *branch) {
+                Ok(branch_context) => {
+                    *evaluation_context = branch_context;
+                    evaluation_context
+                        .indices
+                        .insert("combinator_branch", index);
+
+                    return Ok(());
+                }
+                Err(error) => msg!("Combinator branch {} failed: {:?}", index, error),
+            }
+        }
+
+        err!(CandyGuardError::CombinatorConditionNotMet)
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let branch = self.branches[evaluation_context.indices["combinator_branch"]];
+
+        for condition in guard_set.conditions(branch) {
+            condition.pre_actions(ctx, mint_args, guard_set, evaluation_context)?;
+        }
+
+        Ok(())
+    }
+
+    fn post_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let branch = self.branches[evaluation_context.indices["combinator_branch"]];
+
+        for condition in guard_set.conditions(branch) {
+            condition.post_actions(ctx, mint_args, guard_set, evaluation_context)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{CandyGuardData, Group};
+
+    fn combinator(branches: Vec<u64>) -> Combinator {
+        Combinator {
+            branches,
+            negated: 0,
+        }
+    }
+
+    #[test]
+    fn combined_guards_must_be_enabled() {
+        let mut guards = GuardSet {
+            start_date: Some(StartDate { date: 0 }),
+            combinator: Some(combinator(vec![StartDate::mask() | EndDate::mask()])),
+            ..Default::default()
+        };
+
+        // the end date is not enabled
+        assert_eq!(
+            guards
+                .combinator
+                .as_ref()
+                .unwrap()
+                .assert_members(&guards)
+                .unwrap_err(),
+            error!(CandyGuardError::InvalidCombinator)
+        );
+
+        guards.end_date = Some(EndDate { date: 0 });
+        assert!(guards
+            .combinator
+            .as_ref()
+            .unwrap()
+            .assert_members(&guards)
+            .is_ok());
+    }
+
+    #[test]
+    fn save_rejects_missing_combined_guards() {
+        let mut candy_guard_data = CandyGuardData {
+            default: GuardSet {
+                start_date: Some(StartDate { date: 0 }),
+                combinator: Some(combinator(vec![StartDate::mask(), EndDate::mask()])),
+                ..Default::default()
+            },
+            groups: None,
+        };
+        let mut data = vec![0; candy_guard_data.size()];
+        assert_eq!(
+            candy_guard_data.save(&mut data).unwrap_err(),
+            error!(CandyGuardError::InvalidCombinator)
+        );
+
+        // a group combinator can combine guards of the default guard set
+        let mut group = candy_guard_data.default.clone();
+        candy_guard_data.default.combinator = None;
+        group.start_date = None;
+        group.end_date = Some(EndDate { date: 0 });
+        candy_guard_data.groups = Some(vec![Group {
+            label: "vip".to_string(),
+            guards: group,
+        }]);
+        let mut data = vec![0; candy_guard_data.size()];
+        assert!(candy_guard_data.save(&mut data).is_ok());
+    }
+}
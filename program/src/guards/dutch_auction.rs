@@ -0,0 +1,218 @@
+use super::*;
+
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{errors::CandyGuardError, utils::assert_keys_equal};
+
+/// Guard that charges an amount in SOL (lamports) for the mint, where the price
+/// decreases over time from a start price to a floor (end) price.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Account to receive the funds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct DutchAuction {
+    /// Price (in lamports) at the start of the auction.
+    pub start_price: u64,
+    /// Floor price (in lamports) at the end of the auction.
+    pub end_price: u64,
+    /// Timestamp of the start of the auction.
+    pub start_time: i64,
+    /// Timestamp of the end of the auction.
+    pub end_time: i64,
+    /// Interval (in seconds) between price drops. A value of `0` decreases the price
+    /// linearly over time.
+    pub step_interval: i64,
+    /// Account to receive the funds.
+    pub destination: Pubkey,
+}
+
+impl DutchAuction {
+    /// Returns the price (in lamports) at the specified timestamp.
+    pub fn price_at(&self, timestamp: i64) -> Result<u64> {
+        if timestamp <= self.start_time {
+            return Ok(self.start_price);
+        } else if timestamp >= self.end_time {
+            return Ok(self.end_price);
+        }
+
+        let mut elapsed = timestamp
+            .checked_sub(self.start_time)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        if self.step_interval > 0 {
+            // only considers completed steps
+            elapsed -= elapsed % self.step_interval;
+        }
+
+        let duration = self
+            .end_time
+            .checked_sub(self.start_time)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+        let decrease = ((self.start_price - self.end_price) as u128)
+            .checked_mul(elapsed as u128)
+            .and_then(|amount| amount.checked_div(duration as u128))
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        Ok(self.start_price - decrease as u64)
+    }
+}
+
+impl Guard for DutchAuction {
+    fn size() -> usize {
+        8    // start price
+        + 8  // end price
+        + 8  // start time
+        + 8  // end time
+        + 8  // step interval
+        + 32 // destination
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 21
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.start_price < self.end_price
+            || self.start_time >= self.end_time
+            || self.step_interval < 0
+        {
+            return err!(CandyGuardError::InvalidDutchAuction);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for DutchAuction {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        let destination = Self::get_account_info(ctx, index)?;
+        evaluation_context.account_cursor += 1;
+        // validates the account information
+        assert_keys_equal(destination.key, &self.destination)?;
+
+        evaluation_context
+            .indices
+            .insert("dutch_auction_destination", index);
+
+        let price = evaluation_context.scale(self.price_at(Clock::get()?.unix_timestamp)?)?;
+
+        if ctx.accounts.payer.lamports() < price {
+            msg!(
+                "Require {} lamports, accounts has {} lamports",
+                price,
+                ctx.accounts.payer.lamports(),
+            );
+            return err!(CandyGuardError::NotEnoughSOL);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let destination =
+            Self::get_account_info(ctx, evaluation_context.indices["dutch_auction_destination"])?;
+        // the clock does not change within the transaction, so the price is the
+        // same as the one validated
+        let price = evaluation_context.scale(self.price_at(Clock::get()?.unix_timestamp)?)?;
+
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), price),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                destination.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        evaluation_context.charge(GuardType::DutchAuction, None, price);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn auction(start_price: u64, end_price: u64, step_interval: i64) -> DutchAuction {
+        DutchAuction {
+            start_price,
+            end_price,
+            start_time: 1_000,
+            end_time: 2_000,
+            step_interval,
+            destination: Pubkey::default(),
+        }
+    }
+
+    #[test]
+    fn price_outside_the_auction() {
+        let guard = auction(1_000_000, 100_000, 0);
+
+        assert_eq!(guard.price_at(0).unwrap(), 1_000_000);
+        assert_eq!(guard.price_at(1_000).unwrap(), 1_000_000);
+        assert_eq!(guard.price_at(2_000).unwrap(), 100_000);
+        assert_eq!(guard.price_at(i64::MAX).unwrap(), 100_000);
+    }
+
+    #[test]
+    fn price_decreases_linearly() {
+        let guard = auction(1_000_000, 100_000, 0);
+
+        assert_eq!(guard.price_at(1_001).unwrap(), 999_100);
+        assert_eq!(guard.price_at(1_500).unwrap(), 550_000);
+        assert_eq!(guard.price_at(1_999).unwrap(), 100_900);
+    }
+
+    #[test]
+    fn price_decreases_in_steps() {
+        let guard = auction(1_000_000, 100_000, 250);
+
+        assert_eq!(guard.price_at(1_249).unwrap(), 1_000_000);
+        assert_eq!(guard.price_at(1_250).unwrap(), 775_000);
+        assert_eq!(guard.price_at(1_999).unwrap(), 325_000);
+    }
+
+    #[test]
+    fn price_with_extreme_bounds() {
+        let guard = auction(u64::MAX, 0, 0);
+        assert_eq!(guard.price_at(1_500).unwrap(), u64::MAX - u64::MAX / 2);
+
+        // the duration of the auction does not fit in an i64
+        let mut guard = auction(1_000_000, 100_000, 0);
+        guard.start_time = i64::MIN;
+        guard.end_time = i64::MAX;
+        assert_eq!(
+            guard.price_at(0).unwrap_err(),
+            error!(CandyGuardError::NumericalOverflowError)
+        );
+    }
+}
@@ -0,0 +1,700 @@
+use mpl_candy_machine_core::CandyMachine;
+use mpl_token_metadata::instruction::{freeze_delegated_account, thaw_delegated_account};
+use solana_program::{
+    program::{invoke, invoke_signed},
+    program_option::COption,
+    program_pack::Pack,
+    system_instruction, system_program,
+};
+use spl_associated_token_account::get_associated_token_address;
+use spl_token::{
+    instruction::{approve, revoke},
+    state::{Account as TokenAccount, AccountState},
+};
+
+use super::*;
+use crate::{
+    errors::CandyGuardError,
+    instructions::{Route, ITEM_ACCOUNTS},
+    utils::{assert_keys_equal, assert_owned_by, cmp_pubkeys},
+};
+
+/// Maximum freeze period in seconds (30 days).
+pub const MAX_FREEZE_TIME: i64 = 60 * 60 * 24 * 30;
+
+// Seed value for the freeze escrow PDA.
+pub const FREEZE_SEED: &[u8] = b"freeze_escrow";
+
+/// Guard that charges an amount in SOL (lamports) for the mint with a freeze period.
+/// The funds are held in an escrow account until the minted NFTs are thawed.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Freeze PDA to receive the funds (seeds `["freeze_escrow",
+///                   destination pubkey, candy guard pubkey, candy machine pubkey]`).
+///   1. `[writable]` Associate token account of the NFT (seeds `[payer pubkey,
+///                   token program pubkey, nft mint pubkey]`).
+///   2. `[writable]` Associate token account of each additional NFT when multiple
+///                   items are minted (one account per item, in the same order as the items).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FreezeSolPayment {
+    pub lamports: u64,
+    pub destination: Pubkey,
+}
+
+impl Guard for FreezeSolPayment {
+    fn size() -> usize {
+        8    // lamports
+        + 32 // destination
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 16
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    /// Instructions to interact with the freeze feature:
+    ///
+    ///  * initialize
+    ///  * thaw
+    ///  * unlock funds
+    fn instruction<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        guard_set: &GuardSet,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let destination = if let Some(freeze_sol_payment) = &guard_set.freeze_sol_payment {
+            freeze_sol_payment.destination
+        } else {
+            return err!(CandyGuardError::FreezeGuardNotEnabled);
+        };
+
+        match FreezeInstruction::from_data(&data)? {
+            // Initializes the freeze escrow PDA.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA to receive the funds (seeds `["freeze_escrow",
+            //                   destination pubkey, candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[]` System program account.
+            FreezeInstruction::Initialize => {
+                msg!("Instruction: Initialize (FreezeSolPayment guard)");
+                initialize_freeze(ctx, &data[1..], destination)
+            }
+            // Thaw an eligible NFT.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination pubkey,
+            //                   candy guard pubkey, candy machine pubkey]`).
+            //   1. `[]` Mint account for the NFT.
+            //   2. `[]` Address of the owner of the NFT.
+            //   3. `[writable]` Associate token account of the NFT.
+            //   4. `[]` Master Edition account of the NFT.
+            //   5. `[]` spl-token program ID.
+            //   6. `[]` Metaplex Token Metadata program.
+            FreezeInstruction::Thaw => {
+                msg!("Instruction: Thaw (FreezeSolPayment guard)");
+                thaw_nft(ctx, destination)
+            }
+            // Unlocks frozen funds.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination pubkey,
+            //                   candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[writable]` Address to receive the funds (must match the `destination`
+            //                   address of the guard).
+            FreezeInstruction::UnlockFunds => {
+                msg!("Instruction: Unlock Funds (FreezeSolPayment guard)");
+                unlock_funds(ctx, destination)
+            }
+        }
+    }
+}
+
+impl Condition for FreezeSolPayment {
+    /// Accounts used when a single item is minted.
+    fn accounts_count(&self) -> Option<usize> {
+        Some(2)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        let quantity = evaluation_context.quantity;
+        // validates that we received all required accounts
+        let freeze_pda = Self::get_account_info(ctx, index)?;
+        evaluation_context.account_cursor += 1 + quantity as usize;
+
+        assert_freeze_escrow(
+            freeze_pda,
+            &self.destination,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        )?;
+
+        assert_nft_atas(
+            ctx,
+            nft_ata_indices(index + 1, index + 2, quantity),
+            quantity,
+        )?;
+
+        evaluation_context
+            .indices
+            .insert("freeze_sol_payment", index);
+
+        let lamports = evaluation_context.scale(self.lamports)?;
+
+        if ctx.accounts.payer.lamports() < lamports {
+            msg!(
+                "Require {} lamports, accounts has {} lamports",
+                lamports,
+                ctx.accounts.payer.lamports(),
+            );
+            return err!(CandyGuardError::NotEnoughSOL);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let freeze_pda =
+            Self::get_account_info(ctx, evaluation_context.indices["freeze_sol_payment"])?;
+        let lamports = evaluation_context.scale(self.lamports)?;
+
+        invoke(
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &freeze_pda.key(), lamports),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                freeze_pda.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+
+        evaluation_context.charge(GuardType::FreezeSolPayment, None, lamports);
+
+        Ok(())
+    }
+
+    fn post_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["freeze_sol_payment"];
+        let quantity = evaluation_context.quantity;
+
+        freeze_nfts(
+            ctx,
+            index,
+            nft_ata_indices(index + 1, index + 2, quantity),
+            quantity,
+            &self.destination,
+        )
+    }
+}
+
+/// PDA to store the frozen funds.
+#[account]
+#[derive(Default, Debug)]
+pub struct FreezeEscrow {
+    /// Candy guard address associated with this escrow.
+    pub candy_guard: Pubkey,
+    /// Candy machine address associated with this escrow.
+    pub candy_machine: Pubkey,
+    /// Number of NFTs frozen.
+    pub frozen_count: u64,
+    /// The timestamp of the first (frozen) mint. This is used to calculate
+    /// when the freeze period is over.
+    pub first_mint_time: Option<i64>,
+    /// The amount of time (in seconds) for the freeze. The NFTs will be
+    /// allowed to thaw after this.
+    pub freeze_period: i64,
+    /// The destination address for the frozen fund to go to.
+    pub destination: Pubkey,
+}
+
+impl FreezeEscrow {
+    /// Maximum account size.
+    pub const SIZE: usize = 8  // discriminator
+        + 32                   // candy guard
+        + 32                   // candy machine
+        + 8                    // frozen count
+        + 1 + 8                // option + first mint time
+        + 8                    // freeze period
+        + 32; // destination
+
+    /// Returns true if the freeze period is over or the candy machine has
+    /// minted all items.
+    pub fn is_thaw_allowed(&self, candy_machine: &CandyMachine, current_timestamp: i64) -> bool {
+        if candy_machine.items_redeemed >= candy_machine.data.items_available {
+            return true;
+        }
+
+        if let Some(first_mint_time) = self.first_mint_time {
+            first_mint_time.saturating_add(self.freeze_period) <= current_timestamp
+        } else {
+            false
+        }
+    }
+
+    /// Loads the escrow information from the account.
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        let account_data = account.try_borrow_data()?;
+        Self::try_deserialize(&mut &account_data[..])
+    }
+
+    /// Saves the escrow information back to the account.
+    pub fn save(&self, account: &AccountInfo) -> Result<()> {
+        let mut account_data = account.try_borrow_mut_data()?;
+        self.try_serialize(&mut &mut account_data[..])
+    }
+}
+
+/// Instructions available for the freeze guards.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub enum FreezeInstruction {
+    Initialize,
+    Thaw,
+    UnlockFunds,
+}
+
+impl FreezeInstruction {
+    /// Reads the instruction from the first byte of the route data.
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        if data.is_empty() {
+            return err!(CandyGuardError::MissingFreezeInstruction);
+        }
+
+        Self::try_from_slice(&data[0..1])
+            .map_err(|_| error!(CandyGuardError::MissingFreezeInstruction))
+    }
+}
+
+/// Derives the freeze escrow PDA.
+pub fn find_freeze_pda(
+    destination: &Pubkey,
+    candy_guard: &Pubkey,
+    candy_machine: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            FREEZE_SEED,
+            destination.as_ref(),
+            candy_guard.as_ref(),
+            candy_machine.as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Validates that the account is the (initialized) freeze escrow PDA.
+pub fn assert_freeze_escrow(
+    freeze_pda: &AccountInfo,
+    destination: &Pubkey,
+    candy_guard: &Pubkey,
+    candy_machine: &Pubkey,
+) -> Result<u8> {
+    let (pda, bump) = find_freeze_pda(destination, candy_guard, candy_machine);
+    assert_keys_equal(freeze_pda.key, &pda)?;
+
+    if freeze_pda.data_is_empty() {
+        return err!(CandyGuardError::FreezeNotInitialized);
+    }
+    // if it an existing account, make sure it has the correct ownwer
+    assert_owned_by(freeze_pda, &crate::ID)?;
+
+    Ok(bump)
+}
+
+/// Helper function to initialize the freeze pda.
+pub fn initialize_freeze<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    data: &[u8],
+    destination: Pubkey,
+) -> Result<()> {
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let freeze_pda = FreezeSolPayment::get_account_info(ctx, 0)?;
+    let (pda, bump) = find_freeze_pda(&destination, candy_guard_key, candy_machine_key);
+    assert_keys_equal(freeze_pda.key, &pda)?;
+
+    let authority = FreezeSolPayment::get_account_info(ctx, 1)?;
+
+    if !(cmp_pubkeys(authority.key, &ctx.accounts.candy_guard.authority) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    if !freeze_pda.data_is_empty() {
+        return err!(CandyGuardError::FreezeEscrowAlreadyExists);
+    }
+
+    let freeze_period = if data.len() >= 8 {
+        i64::from_le_bytes(*arrayref::array_ref![data, 0, 8])
+    } else {
+        return err!(CandyGuardError::MissingFreezePeriod);
+    };
+
+    if freeze_period <= 0 || freeze_period > MAX_FREEZE_TIME {
+        return err!(CandyGuardError::ExceededMaximumFreezePeriod);
+    }
+
+    let system_program = FreezeSolPayment::get_account_info(ctx, 2)?;
+    assert_keys_equal(system_program.key, &system_program::ID)?;
+
+    let signer = [
+        FREEZE_SEED,
+        destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+    let rent = Rent::get()?;
+
+    invoke_signed(
+        &system_instruction::create_account(
+            &ctx.accounts.payer.key(),
+            &pda,
+            rent.minimum_balance(FreezeEscrow::SIZE),
+            FreezeEscrow::SIZE as u64,
+            &crate::ID,
+        ),
+        &[
+            ctx.accounts.payer.to_account_info(),
+            freeze_pda.to_account_info(),
+            system_program.to_account_info(),
+        ],
+        &[&signer],
+    )?;
+
+    FreezeEscrow {
+        candy_guard: *candy_guard_key,
+        candy_machine: *candy_machine_key,
+        frozen_count: 0,
+        first_mint_time: None,
+        freeze_period,
+        destination,
+    }
+    .save(freeze_pda)
+}
+
+/// Returns the indices of the token accounts of the NFTs minted in the transaction: the
+/// token account of the first item at `index`, followed by the token accounts of the
+/// additional items starting at `additional_index`.
+pub fn nft_ata_indices(
+    index: usize,
+    additional_index: usize,
+    quantity: u8,
+) -> impl Iterator<Item = usize> {
+    std::iter::once(index).chain(additional_index..additional_index + quantity as usize - 1)
+}
+
+/// Returns the mint and master edition accounts of the NFTs minted in the transaction.
+/// The accounts of the additional items are the last remaining accounts.
+fn minted_nfts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    quantity: u8,
+) -> Result<Vec<(AccountInfo<'info>, AccountInfo<'info>)>> {
+    let item_accounts = (quantity as usize - 1) * ITEM_ACCOUNTS;
+    let start = ctx
+        .remaining_accounts
+        .len()
+        .checked_sub(item_accounts)
+        .ok_or(CandyGuardError::MissingRemainingAccount)?;
+
+    let mut nfts = vec![(
+        ctx.accounts.nft_mint.to_account_info(),
+        ctx.accounts.nft_master_edition.to_account_info(),
+    )];
+    nfts.extend(
+        ctx.remaining_accounts[start..]
+            .chunks(ITEM_ACCOUNTS)
+            .map(|item| (item[0].clone(), item[2].clone())),
+    );
+
+    Ok(nfts)
+}
+
+/// Validates that the token accounts at the specified indices are the associated token
+/// accounts of the payer for the NFTs minted in the transaction.
+pub fn assert_nft_atas<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    indices: impl Iterator<Item = usize>,
+    quantity: u8,
+) -> Result<()> {
+    for (index, (nft_mint, _)) in indices.zip(minted_nfts(ctx, quantity)?) {
+        let nft_ata = FreezeSolPayment::get_account_info(ctx, index)?;
+        assert_keys_equal(
+            nft_ata.key,
+            &get_associated_token_address(ctx.accounts.payer.key, nft_mint.key),
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Helper function to freeze the NFTs minted in the transaction. This is called after
+/// the mint, when the NFT token accounts are available. The freeze PDA becomes the
+/// delegate of the token accounts.
+pub fn freeze_nfts<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    index: usize,
+    nft_ata_indices: impl Iterator<Item = usize>,
+    quantity: u8,
+    destination: &Pubkey,
+) -> Result<()> {
+    let candy_guard_key = &ctx.accounts.candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let freeze_pda = FreezeSolPayment::get_account_info(ctx, index)?;
+
+    let mut freeze_escrow = FreezeEscrow::load(freeze_pda)?;
+    freeze_escrow.frozen_count = freeze_escrow
+        .frozen_count
+        .checked_add(quantity as u64)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+    if freeze_escrow.first_mint_time.is_none() {
+        freeze_escrow.first_mint_time = Some(Clock::get()?.unix_timestamp);
+    }
+
+    freeze_escrow.save(freeze_pda)?;
+
+    let (_, bump) = find_freeze_pda(destination, candy_guard_key, candy_machine_key);
+    let signer = [
+        FREEZE_SEED,
+        destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    for (nft_ata_index, (nft_mint, nft_master_edition)) in
+        nft_ata_indices.zip(minted_nfts(ctx, quantity)?)
+    {
+        let nft_ata = FreezeSolPayment::get_account_info(ctx, nft_ata_index)?;
+
+        // the freeze PDA needs to be the delegate of the token account in order
+        // to freeze it
+
+        invoke(
+            &approve(
+                &spl_token::ID,
+                nft_ata.key,
+                freeze_pda.key,
+                ctx.accounts.payer.key,
+                &[],
+                1,
+            )?,
+            &[
+                nft_ata.to_account_info(),
+                freeze_pda.to_account_info(),
+                ctx.accounts.payer.to_account_info(),
+                ctx.accounts.token_program.to_account_info(),
+            ],
+        )?;
+
+        invoke_signed(
+            &freeze_delegated_account(
+                mpl_token_metadata::ID,
+                freeze_pda.key(),
+                nft_ata.key(),
+                nft_master_edition.key(),
+                nft_mint.key(),
+            ),
+            &[
+                freeze_pda.to_account_info(),
+                nft_ata.to_account_info(),
+                nft_master_edition,
+                nft_mint,
+                ctx.accounts.token_program.to_account_info(),
+                ctx.accounts.token_metadata_program.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Helper function to thaw an NFT. The NFT can be thawed by anyone once the
+/// freeze period is over (or the candy machine has minted all items), or by the
+/// candy guard authority at any time.
+pub fn thaw_nft<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    destination: Pubkey,
+) -> Result<()> {
+    let candy_guard = &ctx.accounts.candy_guard;
+    let candy_guard_key = &candy_guard.key();
+    let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+    let freeze_pda = FreezeSolPayment::get_account_info(ctx, 0)?;
+    let bump = assert_freeze_escrow(freeze_pda, &destination, candy_guard_key, candy_machine_key)?;
+
+    let mut freeze_escrow = FreezeEscrow::load(freeze_pda)?;
+
+    let is_authority = cmp_pubkeys(ctx.accounts.payer.key, &candy_guard.authority);
+
+    if !(is_authority
+        || freeze_escrow.is_thaw_allowed(&ctx.accounts.candy_machine, Clock::get()?.unix_timestamp))
+    {
+        return err!(CandyGuardError::ThawNotEnabled);
+    }
+
+    let nft_mint = FreezeSolPayment::get_account_info(ctx, 1)?;
+    let nft_owner = FreezeSolPayment::get_account_info(ctx, 2)?;
+    let nft_ata = FreezeSolPayment::get_account_info(ctx, 3)?;
+    let nft_master_edition = FreezeSolPayment::get_account_info(ctx, 4)?;
+    let token_program = FreezeSolPayment::get_account_info(ctx, 5)?;
+    let token_metadata_program = FreezeSolPayment::get_account_info(ctx, 6)?;
+
+    assert_keys_equal(token_program.key, &spl_token::ID)?;
+    assert_keys_equal(token_metadata_program.key, &mpl_token_metadata::ID)?;
+    assert_keys_equal(
+        nft_ata.key,
+        &get_associated_token_address(nft_owner.key, nft_mint.key),
+    )?;
+    assert_owned_by(nft_ata, &spl_token::ID)?;
+
+    // only NFTs frozen by the escrow are thawed (and counted)
+    let token_account = TokenAccount::unpack(&nft_ata.try_borrow_data()?)?;
+
+    if token_account.state != AccountState::Frozen
+        || token_account.delegate != COption::Some(*freeze_pda.key)
+    {
+        return err!(CandyGuardError::ThawNotEnabled);
+    }
+
+    let signer = [
+        FREEZE_SEED,
+        destination.as_ref(),
+        candy_guard_key.as_ref(),
+        candy_machine_key.as_ref(),
+        &[bump],
+    ];
+
+    invoke_signed(
+        &thaw_delegated_account(
+            mpl_token_metadata::ID,
+            freeze_pda.key(),
+            nft_ata.key(),
+            nft_master_edition.key(),
+            nft_mint.key(),
+        ),
+        &[
+            freeze_pda.to_account_info(),
+            nft_ata.to_account_info(),
+            nft_master_edition.to_account_info(),
+            nft_mint.to_account_info(),
+            token_program.to_account_info(),
+            token_metadata_program.to_account_info(),
+        ],
+        &[&signer],
+    )?;
+
+    // the delegate can only be removed by the owner of the NFT
+    if nft_owner.is_signer {
+        invoke(
+            &revoke(&spl_token::ID, nft_ata.key, nft_owner.key, &[])?,
+            &[
+                nft_ata.to_account_info(),
+                nft_owner.to_account_info(),
+                token_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    freeze_escrow.frozen_count = freeze_escrow.frozen_count.saturating_sub(1);
+    freeze_escrow.save(freeze_pda)
+}
+
+/// Helper function to validate that the funds of the freeze escrow can be
+/// unlocked. This requires the candy guard authority, that all NFTs have been
+/// thawed and that the freeze period is over (or the candy machine has minted
+/// all items).
+pub fn assert_unlock_allowed<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    freeze_pda: &AccountInfo,
+    destination: &Pubkey,
+) -> Result<FreezeEscrow> {
+    let candy_guard = &ctx.accounts.candy_guard;
+
+    assert_freeze_escrow(
+        freeze_pda,
+        destination,
+        &candy_guard.key(),
+        &ctx.accounts.candy_machine.key(),
+    )?;
+
+    let authority = FreezeSolPayment::get_account_info(ctx, 1)?;
+
+    if !(cmp_pubkeys(authority.key, &candy_guard.authority) && authority.is_signer) {
+        return err!(CandyGuardError::MissingRequiredSignature);
+    }
+
+    let freeze_escrow = FreezeEscrow::load(freeze_pda)?;
+
+    if freeze_escrow.frozen_count > 0 {
+        return err!(CandyGuardError::UnlockNotEnabled);
+    }
+
+    if freeze_escrow.first_mint_time.is_some()
+        && !freeze_escrow.is_thaw_allowed(&ctx.accounts.candy_machine, Clock::get()?.unix_timestamp)
+    {
+        return err!(CandyGuardError::UnlockNotEnabled);
+    }
+
+    Ok(freeze_escrow)
+}
+
+/// Helper function to unlock funds. The freeze escrow account is closed and all
+/// its lamports are transferred to the destination address.
+fn unlock_funds<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+    destination: Pubkey,
+) -> Result<()> {
+    let freeze_pda = FreezeSolPayment::get_account_info(ctx, 0)?;
+    assert_unlock_allowed(ctx, freeze_pda, &destination)?;
+
+    let destination_address = FreezeSolPayment::get_account_info(ctx, 2)?;
+    assert_keys_equal(destination_address.key, &destination)?;
+
+    close_freeze_escrow(freeze_pda, destination_address)
+}
+
+/// Closes the freeze escrow account, transferring its lamports to the
+/// specified account.
+pub fn close_freeze_escrow(freeze_pda: &AccountInfo, recipient: &AccountInfo) -> Result<()> {
+    let lamports = freeze_pda.lamports();
+
+    **recipient.lamports.borrow_mut() = recipient
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **freeze_pda.lamports.borrow_mut() = 0;
+
+    let mut account_data = freeze_pda.try_borrow_mut_data()?;
+    account_data.fill(0);
+
+    Ok(())
+}
@@ -0,0 +1,345 @@
+use solana_program::program::{invoke, invoke_signed};
+use spl_associated_token_account::{
+    get_associated_token_address, instruction::create_associated_token_account,
+};
+use spl_token::{instruction::close_account, state::Account as TokenAccount};
+
+use super::{freeze_sol_payment::*, *};
+use crate::{
+    errors::CandyGuardError,
+    instructions::Route,
+    utils::{
+        assert_initialized, assert_is_ata, assert_is_mint, assert_keys_equal, assert_owned_by,
+        spl_token_transfer, TokenTransferParams,
+    },
+};
+
+/// Guard that charges an amount in a specified spl-token as payment for the mint with
+/// a freeze period. The tokens are held in an escrow token account until the minted
+/// NFTs are thawed.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey,
+///                   candy guard pubkey, candy machine pubkey]`).
+///   1. `[writable]` Associate token account of the NFT (seeds `[payer pubkey,
+///                   token program pubkey, nft mint pubkey]`).
+///   2. `[writable]` Token account holding the required amount.
+///   3. `[writable]` Associate token account of the Freeze PDA (seeds `[freeze PDA
+///                   pubkey, token program pubkey, token mint pubkey]`).
+///   4. `[]` Token mint account.
+///   5. `[writable]` Associate token account of each additional NFT when multiple
+///                   items are minted (one account per item, in the same order as the items).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FreezeTokenPayment {
+    pub amount: u64,
+    pub mint: Pubkey,
+    pub destination_ata: Pubkey,
+}
+
+impl Guard for FreezeTokenPayment {
+    fn size() -> usize {
+        8    // amount
+        + 32 // token mint
+        + 32 // destination ata
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 17
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    /// Instructions to interact with the freeze feature:
+    ///
+    ///  * initialize
+    ///  * thaw
+    ///  * unlock funds
+    fn instruction<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        guard_set: &GuardSet,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        let freeze_guard = if let Some(freeze_token_payment) = &guard_set.freeze_token_payment {
+            freeze_token_payment
+        } else {
+            return err!(CandyGuardError::FreezeGuardNotEnabled);
+        };
+
+        match FreezeInstruction::from_data(&data)? {
+            // Initializes the freeze escrow PDA and its token account.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey,
+            //                   candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer]` Candy Guard authority.
+            //   2. `[]` System program account.
+            //   3. `[writable]` Associate token account of the Freeze PDA (seeds `[freeze PDA
+            //                   pubkey, token program pubkey, token mint pubkey]`).
+            //   4. `[]` Token mint account.
+            //   5. `[]` spl-token program ID.
+            //   6. `[]` spl-associate-token program ID.
+            //   7. `[]` Address of the ATA to receive the tokens.
+            FreezeInstruction::Initialize => {
+                msg!("Instruction: Initialize (FreezeTokenPayment guard)");
+                freeze_guard.initialize_escrow(ctx, &data[1..])
+            }
+            // Thaw an eligible NFT.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey,
+            //                   candy guard pubkey, candy machine pubkey]`).
+            //   1. `[]` Mint account for the NFT.
+            //   2. `[]` Address of the owner of the NFT.
+            //   3. `[writable]` Associate token account of the NFT.
+            //   4. `[]` Master Edition account of the NFT.
+            //   5. `[]` spl-token program ID.
+            //   6. `[]` Metaplex Token Metadata program.
+            FreezeInstruction::Thaw => {
+                msg!("Instruction: Thaw (FreezeTokenPayment guard)");
+                thaw_nft(ctx, freeze_guard.destination_ata)
+            }
+            // Unlocks frozen funds.
+            //
+            // List of accounts required:
+            //
+            //   0. `[writable]` Freeze PDA (seeds `["freeze_escrow", destination_ata pubkey,
+            //                   candy guard pubkey, candy machine pubkey]`).
+            //   1. `[signer, writable]` Candy Guard authority.
+            //   2. `[writable]` Address of the ATA to receive the tokens (must match the
+            //                   `destination_ata` address of the guard).
+            //   3. `[writable]` Associate token account of the Freeze PDA.
+            //   4. `[]` spl-token program ID.
+            //   5. `[]` Token mint account.
+            FreezeInstruction::UnlockFunds => {
+                msg!("Instruction: Unlock Funds (FreezeTokenPayment guard)");
+                freeze_guard.unlock_funds(ctx)
+            }
+        }
+    }
+}
+
+impl Condition for FreezeTokenPayment {
+    /// Accounts used when a single item is minted.
+    fn accounts_count(&self) -> Option<usize> {
+        Some(5)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        let quantity = evaluation_context.quantity;
+        // validates that we received all required accounts
+        let freeze_pda = Self::get_account_info(ctx, index)?;
+        let token_account_info = Self::get_account_info(ctx, index + 2)?;
+        let freeze_ata = Self::get_account_info(ctx, index + 3)?;
+        let token_mint = Self::get_account_info(ctx, index + 4)?;
+        evaluation_context.account_cursor += 4 + quantity as usize;
+
+        assert_freeze_escrow(
+            freeze_pda,
+            &self.destination_ata,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        )?;
+
+        assert_nft_atas(
+            ctx,
+            nft_ata_indices(index + 1, index + 5, quantity),
+            quantity,
+        )?;
+
+        assert_is_ata(freeze_ata, freeze_pda.key, &self.mint)?;
+
+        assert_keys_equal(token_mint.key, &self.mint)?;
+        assert_owned_by(token_mint, &spl_token::ID)?;
+
+        let token_account =
+            assert_is_ata(token_account_info, &ctx.accounts.payer.key(), &self.mint)?;
+
+        if token_account.amount < evaluation_context.scale(self.amount)? {
+            return err!(CandyGuardError::NotEnoughTokens);
+        }
+
+        evaluation_context
+            .indices
+            .insert("freeze_token_payment", index);
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["freeze_token_payment"];
+        // the accounts have already been validated
+        let token_account_info = Self::get_account_info(ctx, index + 2)?;
+        let freeze_ata = Self::get_account_info(ctx, index + 3)?;
+        let token_mint = Self::get_account_info(ctx, index + 4)?;
+        let amount = evaluation_context.scale(self.amount)?;
+
+        spl_token_transfer(TokenTransferParams {
+            source: token_account_info.to_account_info(),
+            destination: freeze_ata.to_account_info(),
+            mint: token_mint.to_account_info(),
+            authority: ctx.accounts.payer.to_account_info(),
+            authority_signer_seeds: &[],
+            token_program: ctx.accounts.token_program.to_account_info(),
+            amount,
+            decimals: assert_is_mint(token_mint)?.decimals,
+        })?;
+
+        evaluation_context.charge(GuardType::FreezeTokenPayment, Some(self.mint), amount);
+
+        Ok(())
+    }
+
+    fn post_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["freeze_token_payment"];
+        let quantity = evaluation_context.quantity;
+
+        freeze_nfts(
+            ctx,
+            index,
+            nft_ata_indices(index + 1, index + 5, quantity),
+            quantity,
+            &self.destination_ata,
+        )
+    }
+}
+
+impl FreezeTokenPayment {
+    /// Initializes the freeze escrow PDA and the token account to hold the
+    /// escrow funds.
+    fn initialize_escrow<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        data: &[u8],
+    ) -> Result<()> {
+        initialize_freeze(ctx, data, self.destination_ata)?;
+
+        let freeze_pda = Self::get_account_info(ctx, 0)?;
+        let system_program = Self::get_account_info(ctx, 2)?;
+        let freeze_ata = Self::get_account_info(ctx, 3)?;
+        let token_mint = Self::get_account_info(ctx, 4)?;
+        let token_program = Self::get_account_info(ctx, 5)?;
+        let associated_token_program = Self::get_account_info(ctx, 6)?;
+        let destination_ata = Self::get_account_info(ctx, 7)?;
+
+        assert_keys_equal(token_mint.key, &self.mint)?;
+        assert_keys_equal(token_program.key, &spl_token::ID)?;
+        assert_keys_equal(
+            associated_token_program.key,
+            &spl_associated_token_account::ID,
+        )?;
+        assert_keys_equal(destination_ata.key, &self.destination_ata)?;
+        // the destination must be able to receive the tokens
+        assert_owned_by(destination_ata, &spl_token::ID)?;
+        let destination_account: TokenAccount = assert_initialized(destination_ata)?;
+        assert_keys_equal(&destination_account.mint, &self.mint)?;
+
+        assert_keys_equal(
+            freeze_ata.key,
+            &get_associated_token_address(freeze_pda.key, &self.mint),
+        )?;
+
+        invoke(
+            &create_associated_token_account(
+                ctx.accounts.payer.key,
+                freeze_pda.key,
+                token_mint.key,
+            ),
+            &[
+                ctx.accounts.payer.to_account_info(),
+                freeze_ata.to_account_info(),
+                freeze_pda.to_account_info(),
+                token_mint.to_account_info(),
+                system_program.to_account_info(),
+                token_program.to_account_info(),
+                associated_token_program.to_account_info(),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    /// Transfers the escrow funds to the destination token account and closes the
+    /// escrow accounts. The rent lamports are returned to the authority.
+    fn unlock_funds<'info>(&self, ctx: &Context<'_, '_, '_, 'info, Route<'info>>) -> Result<()> {
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let freeze_pda = Self::get_account_info(ctx, 0)?;
+        assert_unlock_allowed(ctx, freeze_pda, &self.destination_ata)?;
+
+        let authority = Self::get_account_info(ctx, 1)?;
+        let destination_ata = Self::get_account_info(ctx, 2)?;
+        let freeze_ata = Self::get_account_info(ctx, 3)?;
+        let token_program = Self::get_account_info(ctx, 4)?;
+        let token_mint = Self::get_account_info(ctx, 5)?;
+
+        assert_keys_equal(destination_ata.key, &self.destination_ata)?;
+        assert_keys_equal(token_program.key, &spl_token::ID)?;
+        assert_keys_equal(token_mint.key, &self.mint)?;
+        let decimals = assert_is_mint(token_mint)?.decimals;
+        let escrow_account = assert_is_ata(freeze_ata, freeze_pda.key, &self.mint)?;
+
+        let (_, bump) = find_freeze_pda(&self.destination_ata, candy_guard_key, candy_machine_key);
+        let signer = [
+            FREEZE_SEED,
+            self.destination_ata.as_ref(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+            &[bump],
+        ];
+
+        spl_token_transfer(TokenTransferParams {
+            source: freeze_ata.to_account_info(),
+            destination: destination_ata.to_account_info(),
+            mint: token_mint.to_account_info(),
+            authority: freeze_pda.to_account_info(),
+            authority_signer_seeds: &signer,
+            token_program: token_program.to_account_info(),
+            amount: escrow_account.amount,
+            decimals,
+        })?;
+
+        invoke_signed(
+            &close_account(
+                token_program.key,
+                freeze_ata.key,
+                authority.key,
+                freeze_pda.key,
+                &[],
+            )?,
+            &[
+                freeze_ata.to_account_info(),
+                authority.to_account_info(),
+                freeze_pda.to_account_info(),
+                token_program.to_account_info(),
+            ],
+            &[&signer],
+        )?;
+
+        close_freeze_escrow(freeze_pda, authority)
+    }
+}
@@ -35,9 +35,17 @@ impl Guard for Gatekeeper {
     fn mask() -> u64 {
         0b1u64 << 6
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for Gatekeeper {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(if self.expire_on_use { 3 } else { 1 })
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -1,7 +1,11 @@
 use solana_program::{program::invoke_signed, system_instruction};
 
 use super::*;
-use crate::utils::assert_keys_equal;
+use crate::{
+    instructions::Route,
+    state::DelegateRecord,
+    utils::{assert_keys_equal, assert_owned_by},
+};
 
 /// Gaurd to set a limit of mints per wallet.
 ///
@@ -25,6 +29,27 @@ pub struct MintCounter {
     pub count: u16,
 }
 
+impl MintLimit {
+    /// Derives the mint counter PDA of an address.
+    pub fn find_counter_pda(
+        &self,
+        user: &Pubkey,
+        candy_guard: &Pubkey,
+        candy_machine: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"mint_limit".as_ref(),
+                &[self.id],
+                user.as_ref(),
+                candy_guard.as_ref(),
+                candy_machine.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
+
 impl Guard for MintLimit {
     fn size() -> usize {
         1   // id
@@ -34,9 +59,79 @@ impl Guard for MintLimit {
     fn mask() -> u64 {
         0b1u64 << 9
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    /// Closes the mint counter PDA of an address, returning the rent lamports to the
+    /// signer. The instruction data is the address (`Pubkey`) of the mint counter. The
+    /// instruction can be signed by the Candy Guard authority or by a delegate with the
+    /// `CloseCounters` role.
+    ///
+    /// List of accounts required:
+    ///
+    ///   0. `[writable]` Mint counter PDA (seeds `["mint_limit", mint guard id, address,
+    ///                   candy guard pubkey, candy machine pubkey]`).
+    ///   1. `[signer, writable]` Candy Guard authority or delegate.
+    ///   2. `[optional]` Delegate record (only when the signer is a delegate).
+    fn instruction<'info>(
+        ctx: &Context<'_, '_, '_, 'info, Route<'info>>,
+        guard_set: &GuardSet,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        msg!("MintLimit: close counter instruction");
+
+        let mint_limit = if let Some(mint_limit) = &guard_set.mint_limit {
+            mint_limit
+        } else {
+            return err!(CandyGuardError::MintLimitNotEnabled);
+        };
+
+        let user = Pubkey::try_from_slice(&data)
+            .map_err(|_| error!(CandyGuardError::InstructionNotFound))?;
+
+        let counter = Self::get_account_info(ctx, 0)?;
+        let authority = Self::get_account_info(ctx, 1)?;
+
+        let signer = Signer::try_from(authority)
+            .map_err(|_| error!(CandyGuardError::MissingRequiredSignature))?;
+        let delegate_accounts = ctx.remaining_accounts.get(2..).unwrap_or_default();
+
+        if let Some(role) =
+            DelegateRecord::authorize(&ctx.accounts.candy_guard, &signer, delegate_accounts)?
+        {
+            role.assert_close_counters()?;
+        }
+
+        let (pda, _) = mint_limit.find_counter_pda(
+            &user,
+            &ctx.accounts.candy_guard.key(),
+            &ctx.accounts.candy_machine.key(),
+        );
+        assert_keys_equal(counter.key, &pda)?;
+        assert_owned_by(counter, &crate::ID)?;
+
+        let lamports = counter.lamports();
+
+        **authority.lamports.borrow_mut() = authority
+            .lamports()
+            .checked_add(lamports)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+        **counter.lamports.borrow_mut() = 0;
+
+        let mut account_data = counter.try_borrow_mut_data()?;
+        account_data.fill(0);
+
+        Ok(())
+    }
 }
 
 impl Condition for MintLimit {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -65,15 +160,17 @@ impl Condition for MintLimit {
 
         assert_keys_equal(counter.key, &pda)?;
 
+        let quantity = evaluation_context.quantity as u32;
+
         if !counter.data_is_empty() {
             let account_data = counter.data.borrow();
             let mint_counter = MintCounter::try_from_slice(&account_data)?;
 
-            if mint_counter.count >= self.limit {
+            if mint_counter.count as u32 + quantity > self.limit as u32 {
                 return err!(CandyGuardError::AllowedMintLimitReached);
             }
-        } else if self.limit < 1 {
-            // sanity check: if the limit is set to less than 1 we cannot proceed
+        } else if (self.limit as u32) < quantity {
+            // sanity check: if the limit is set to less than the quantity we cannot proceed
             return err!(CandyGuardError::AllowedMintLimitReached);
         }
 
@@ -131,7 +228,8 @@ impl Condition for MintLimit {
 
         let mut account_data = counter.try_borrow_mut_data()?;
         let mut mint_counter = MintCounter::try_from_slice(&account_data)?;
-        mint_counter.count += 1;
+        // the quantity has already been validated against the limit
+        mint_counter.count += evaluation_context.quantity as u16;
         // saves the changes back to the pda
         let data = &mut mint_counter.try_to_vec().unwrap();
         account_data[0..data.len()].copy_from_slice(data);
@@ -3,36 +3,61 @@ use std::collections::BTreeMap;
 pub use anchor_lang::prelude::*;
 
 use crate::instructions::Route;
-pub use crate::{errors::CandyGuardError, instructions::mint::*, state::GuardSet};
+pub use crate::{
+    errors::CandyGuardError,
+    events::GuardCharge,
+    instructions::mint::*,
+    state::{Features, GuardSet, GuardType},
+};
 
 pub use self::token_payment::TokenPayment;
 pub use address_gate::AddressGate;
-pub use allow_list::AllowList;
+pub use allocation::{Allocation, AllocationInstruction, AllocationTracker};
+pub use allow_list::{AllowList, AllowListInstruction, AllowListProof, AllowListQuota};
+pub use bonding_curve::BondingCurve;
 pub use bot_tax::BotTax;
+pub use combinator::{Combinator, MAXIMUM_BRANCHES};
+pub use dutch_auction::DutchAuction;
 pub use end_date::EndDate;
+pub use freeze_sol_payment::{FreezeEscrow, FreezeInstruction, FreezeSolPayment};
+pub use freeze_token_payment::FreezeTokenPayment;
 pub use gatekeeper::Gatekeeper;
 pub use mint_limit::{MintCounter, MintLimit};
 pub use nft_burn::NftBurn;
 pub use nft_gate::NftGate;
 pub use nft_payment::NftPayment;
+pub use program_gate::ProgramGate;
+pub use program_guard::{ProgramGuard, ProgramGuardArgs, ProgramGuardHook};
 pub use redeemed_amount::RedeemedAmount;
+pub use signed_voucher::{SignedVoucher, Voucher};
 pub use sol_payment::SolPayment;
+pub use sol_payment_split::{SolPaymentSplit, SplitDestination};
 pub use start_date::StartDate;
 pub use third_party_signer::ThirdPartySigner;
 pub use token_burn::TokenBurn;
 pub use token_gate::TokenGate;
 
 mod address_gate;
+mod allocation;
 mod allow_list;
+mod bonding_curve;
 mod bot_tax;
+mod combinator;
+mod dutch_auction;
 mod end_date;
+mod freeze_sol_payment;
+mod freeze_token_payment;
 mod gatekeeper;
 mod mint_limit;
 mod nft_burn;
 mod nft_gate;
 mod nft_payment;
+mod program_gate;
+mod program_guard;
 mod redeemed_amount;
+mod signed_voucher;
 mod sol_payment;
+mod sol_payment_split;
 mod start_date;
 mod third_party_signer;
 mod token_burn;
@@ -84,6 +109,16 @@ pub trait Condition {
     ) -> Result<()> {
         Ok(())
     }
+
+    /// Return the number of remaining accounts used by the guard, or `None` when it
+    /// cannot be determined from the guard configuration.
+    ///
+    /// A guard might fail before consuming all of its accounts, so this is used to
+    /// keep the accounts of the following guards aligned when the evaluation continues
+    /// after an error.
+    fn accounts_count(&self) -> Option<usize> {
+        Some(0)
+    }
 }
 
 pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
@@ -93,6 +128,17 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
     /// Return the feature mask for the guard.
     fn mask() -> u64;
 
+    /// Return the index of the word of the feature bitmap that contains the guard
+    /// feature mask. Guards are added to the first word until its 63 flags are used.
+    fn word() -> usize {
+        0
+    }
+
+    /// Return whether the guard uses remaining accounts during the mint.
+    fn uses_accounts() -> bool {
+        false
+    }
+
     /// Executes an instruction. This function is called from the `route` instruction
     /// handler.
     fn instruction<'info>(
@@ -104,18 +150,20 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
     }
 
     /// Returns whether the guards is enabled or not on the specified features.
-    fn is_enabled(features: u64) -> bool {
-        features & Self::mask() > 0
+    fn is_enabled(features: &Features) -> bool {
+        features.contains(Self::word(), Self::mask())
     }
 
     /// Enable the guard on the specified `features` value.
-    fn enable(features: u64) -> u64 {
-        features | Self::mask()
+    fn enable(mut features: Features) -> Features {
+        features.set(Self::word(), Self::mask());
+        features
     }
 
     /// Disable the guard on the specified `features` value.
-    fn disable(features: u64) -> u64 {
-        features & !Self::mask()
+    fn disable(mut features: Features) -> Features {
+        features.clear(Self::word(), Self::mask());
+        features
     }
 
     /// Serialize the guard into the specified data array.
@@ -140,6 +188,19 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
         }
     }
 
+    /// Return the number of bytes used by the guard configuration on the specified
+    /// layout version of the candy guard data. Guards only need to override this
+    /// when their configuration changed since that version.
+    fn size_for(_version: u8) -> usize {
+        Self::size()
+    }
+
+    /// Deserializes the guard stored using the specified layout version of the candy
+    /// guard data, converting it to the current configuration.
+    fn load_for(data: &[u8], offset: usize, _version: u8) -> Result<Option<Self>> {
+        Self::load(data, offset)
+    }
+
     fn get_account_info<'c, 'info, T>(
         ctx: &Context<'_, '_, 'c, 'info, T>,
         index: usize,
@@ -151,6 +212,7 @@ pub trait Guard: Condition + AnchorSerialize + AnchorDeserialize {
         }
     }
 }
+#[derive(Clone)]
 pub struct EvaluationContext<'a> {
     /// The cursor for the remaining account list. When a guard "consumes" one of the
     /// remaining accounts, it should increment the cursor.
@@ -162,4 +224,38 @@ pub struct EvaluationContext<'a> {
 
     /// Convenience mapping of remaining account indices.
     pub indices: BTreeMap<&'a str, usize>,
+
+    /// The number of items being minted in the transaction.
+    pub quantity: u8,
+
+    /// Amounts charged by the guards, reported on the mint event.
+    pub charges: Vec<GuardCharge>,
+}
+
+impl<'a> EvaluationContext<'a> {
+    /// Returns the amount multiplied by the number of items being minted.
+    pub fn scale(&self, amount: u64) -> Result<u64> {
+        amount
+            .checked_mul(self.quantity as u64)
+            .ok_or_else(|| CandyGuardError::NumericalOverflowError.into())
+    }
+
+    /// Records an amount charged by a guard.
+    pub fn charge(&mut self, guard: GuardType, mint: Option<Pubkey>, amount: u64) {
+        self.charges.push(GuardCharge {
+            guard,
+            mint,
+            amount,
+        });
+    }
+
+    /// Validates that a single item is being minted, for guards that cannot be
+    /// applied to multiple items in the same transaction.
+    pub fn assert_single_item(&self) -> Result<()> {
+        if self.quantity > 1 {
+            return err!(CandyGuardError::QuantityNotSupported);
+        }
+
+        Ok(())
+    }
 }
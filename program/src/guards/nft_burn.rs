@@ -10,6 +10,9 @@ use crate::utils::assert_keys_equal;
 
 /// Guard that requires another NFT (token) from a specific collection to be burned.
 ///
+/// Only one NFT is burned, so the guard cannot be used to mint multiple items in the
+/// same transaction (the mint fails with `QuantityNotSupported`).
+///
 /// List of accounts required:
 ///
 ///   0. `[writeable]` Token account of the NFT.
@@ -30,9 +33,17 @@ impl Guard for NftBurn {
     fn mask() -> u64 {
         0b1u64 << 14
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for NftBurn {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(5)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -40,6 +51,8 @@ impl Condition for NftBurn {
         _guard_set: &GuardSet,
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
+        evaluation_context.assert_single_item()?;
+
         let index = evaluation_context.account_cursor;
         // validates that we received all required accounts
         let nft_account = Self::get_account_info(ctx, index)?;
@@ -107,6 +120,8 @@ impl Condition for NftBurn {
             burn_nft_infos.as_slice(),
         )?;
 
+        evaluation_context.charge(GuardType::NftBurn, Some(nft_mint_account.key()), 1);
+
         Ok(())
     }
 }
@@ -24,9 +24,17 @@ impl Guard for NftGate {
     fn mask() -> u64 {
         0b1u64 << 13
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for NftGate {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(2)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
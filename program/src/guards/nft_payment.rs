@@ -7,6 +7,9 @@ use spl_associated_token_account::instruction::create_associated_token_account;
 /// Guard that charges another NFT (token) from a specific collection as payment
 /// for the mint.
 ///
+/// Only one NFT is received as payment, so the guard cannot be used to mint multiple
+/// items in the same transaction (the mint fails with `QuantityNotSupported`).
+///
 /// List of accounts required:
 ///
 ///   0. `[writeable]` Token account of the NFT.
@@ -30,9 +33,17 @@ impl Guard for NftPayment {
     fn mask() -> u64 {
         0b1u64 << 10
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for NftPayment {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(6)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -40,6 +51,8 @@ impl Condition for NftPayment {
         _guard_set: &GuardSet,
         evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
+        evaluation_context.assert_single_item()?;
+
         let index = evaluation_context.account_cursor;
 
         // validates that we received all required accounts
@@ -119,13 +132,17 @@ impl Condition for NftPayment {
         spl_token_transfer(TokenTransferParams {
             source: nft_account.to_account_info(),
             destination: destination_ata.to_account_info(),
+            mint: nft_mint.to_account_info(),
             authority: ctx.accounts.payer.to_account_info(),
             authority_signer_seeds: &[],
             token_program: ctx.accounts.token_program.to_account_info(),
             // fixed to always require 1 NFT
             amount: 1,
+            decimals: 0,
         })?;
 
+        evaluation_context.charge(GuardType::NftPayment, Some(nft_mint.key()), 1);
+
         Ok(())
     }
 }
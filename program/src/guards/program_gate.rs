@@ -0,0 +1,97 @@
+use solana_program::{serialize_utils::read_pubkey, serialize_utils::read_u16, system_program};
+
+use super::*;
+use crate::{errors::CandyGuardError, utils::cmp_pubkeys};
+
+/// Maximum number of additional programs.
+pub const MAXIMUM_SIZE: usize = 5;
+
+/// Programs that are always allowed in a mint transaction.
+pub static DEFAULT_PROGRAMS: &[Pubkey] = &[
+    crate::ID,
+    spl_token::ID,
+    system_program::ID,
+    spl_associated_token_account::ID,
+];
+
+/// Guard that restricts the programs that can be in a mint transaction. The guard
+/// allows the candy guard, token, system and associated token programs by default,
+/// plus the list of `additional` programs.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramGate {
+    pub additional: Vec<Pubkey>,
+}
+
+impl Guard for ProgramGate {
+    fn size() -> usize {
+        4 // u32 (number of addresses)
+        + (MAXIMUM_SIZE * 32) // max list of additional programs
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 18
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.additional.len() > MAXIMUM_SIZE {
+            return err!(CandyGuardError::ExceededProgramListSize);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for ProgramGate {
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        _evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let mut programs: Vec<Pubkey> = DEFAULT_PROGRAMS.to_vec();
+        programs.extend(self.additional.iter());
+
+        Self::verify(&ctx.accounts.instruction_sysvar_account, &programs)
+    }
+}
+
+impl ProgramGate {
+    /// Validates that all instructions of the transaction are from the list of
+    /// specified programs.
+    pub fn verify(instruction_sysvar_account: &AccountInfo, programs: &[Pubkey]) -> Result<()> {
+        let instruction_sysvar = instruction_sysvar_account.data.borrow();
+
+        let mut idx = 0;
+        let num_instructions = read_u16(&mut idx, &instruction_sysvar)
+            .map_err(|_| ProgramError::InvalidAccountData)?;
+
+        for index in 0..num_instructions {
+            let mut current = 2 + (index * 2) as usize;
+            let start = read_u16(&mut current, &instruction_sysvar)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+
+            current = start as usize;
+            let num_accounts = read_u16(&mut current, &instruction_sysvar)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+            current += (num_accounts as usize) * (1 + 32);
+            let program_id = read_pubkey(&mut current, &instruction_sysvar)
+                .map_err(|_| ProgramError::InvalidAccountData)?;
+
+            if !programs
+                .iter()
+                .any(|program| cmp_pubkeys(&program_id, program))
+            {
+                msg!("Transaction had ix with program id {}", program_id);
+                return err!(CandyGuardError::UnauthorizedProgramFound);
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,235 @@
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    program::{get_return_data, invoke, set_return_data},
+};
+
+use super::*;
+use crate::{errors::CandyGuardError, utils::assert_keys_equal};
+
+/// Maximum size of the configuration bytes.
+pub const MAXIMUM_CONFIG_SIZE: usize = 64;
+
+/// Instruction discriminator of the hook (`sha256("global:candy_guard_hook")[..8]`),
+/// so the hook can be implemented as an Anchor instruction named `candy_guard_hook`.
+pub const HOOK_DISCRIMINATOR: [u8; 8] = [111, 144, 254, 107, 12, 212, 71, 70];
+
+/// Guard that delegates the validation and actions of the mint to an external
+/// program. The program receives a CPI for each step of the mint with the
+/// `ProgramGuardArgs` as instruction data, prefixed by the `HOOK_DISCRIMINATOR`,
+/// and the following accounts:
+///
+///   0. `[]` Candy guard account.
+///   1. `[]` Candy machine account.
+///   2. `[]` Payer of the transaction (not a signer).
+///   3. `[]` Mint account of the NFT.
+///   4. Accounts forwarded from the remaining accounts, if any. Forwarded accounts
+///      never have signer privileges and are read-only on the `Validate` hook.
+///
+/// The `Validate` hook must not fail when the requirements of the program are not
+/// met; instead, it must set the return data to a `u32` code, where `0` indicates
+/// that the mint is allowed. A missing return data is considered a rejection. Any
+/// error of the `PreActions` and `PostActions` hooks makes the transaction fail.
+///
+/// List of accounts required:
+///
+///   0. `[]` External program account.
+///   1. Accounts forwarded to the external program, if any (the number of accounts
+///      is specified by `accounts`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramGuard {
+    /// Address of the external program.
+    pub program_id: Pubkey,
+    /// Number of remaining accounts forwarded to the external program.
+    pub accounts: u8,
+    /// Opaque configuration bytes forwarded to the external program.
+    pub config: Vec<u8>,
+}
+
+/// Step of the mint being executed by the external program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProgramGuardHook {
+    Validate,
+    PreActions,
+    PostActions,
+}
+
+/// Instruction data of the CPI to the external program.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ProgramGuardArgs {
+    /// Step of the mint being executed.
+    pub hook: ProgramGuardHook,
+    /// Configuration bytes of the guard.
+    pub config: Vec<u8>,
+    /// Arguments of the mint transaction.
+    pub mint_args: Vec<u8>,
+}
+
+impl ProgramGuard {
+    /// Invokes the hook of the external program. The accounts are expected to have
+    /// been validated.
+    fn invoke_hook<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        index: usize,
+        hook: ProgramGuardHook,
+    ) -> Result<()> {
+        let program = Self::get_account_info(ctx, index)?;
+
+        let mut account_infos = vec![
+            ctx.accounts.candy_guard.to_account_info(),
+            ctx.accounts.candy_machine.to_account_info(),
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.nft_mint.to_account_info(),
+        ];
+        // the accounts of the mint context are read-only and without signer
+        // privileges, so the external program cannot act on behalf of the payer
+        let mut accounts: Vec<AccountMeta> = account_infos
+            .iter()
+            .map(|account| AccountMeta::new_readonly(account.key(), false))
+            .collect();
+
+        for offset in 1..=self.accounts as usize {
+            let account = Self::get_account_info(ctx, index + offset)?;
+
+            // forwarded accounts are also without signer privileges (even if the payer
+            // is forwarded) and are read-only on the validate hook, since validation
+            // must not modify accounts: a rejection is subject to bot tax and, when the
+            // group is selected automatically, the hook runs for each group evaluated
+            let writable = account.is_writable && hook != ProgramGuardHook::Validate;
+
+            accounts.push(if writable {
+                AccountMeta::new(account.key(), false)
+            } else {
+                AccountMeta::new_readonly(account.key(), false)
+            });
+            account_infos.push(account.to_account_info());
+        }
+
+        account_infos.push(program.to_account_info());
+
+        let mut data = HOOK_DISCRIMINATOR.to_vec();
+        ProgramGuardArgs {
+            hook,
+            config: self.config.clone(),
+            mint_args: mint_args.to_vec(),
+        }
+        .serialize(&mut data)?;
+
+        invoke(
+            &Instruction {
+                program_id: self.program_id,
+                accounts,
+                data,
+            },
+            &account_infos,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl Guard for ProgramGuard {
+    fn size() -> usize {
+        32 // program id
+        + 1 // accounts
+        + 4 // u32 (size of the config)
+        + MAXIMUM_CONFIG_SIZE // max config bytes
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 25
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.config.len() > MAXIMUM_CONFIG_SIZE {
+            return err!(CandyGuardError::ExceededProgramGuardConfigSize);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for ProgramGuard {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1 + self.accounts as usize)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        evaluation_context.assert_single_item()?;
+
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        let program = Self::get_account_info(ctx, index)?;
+        let _ = Self::get_account_info(ctx, index + self.accounts as usize)?;
+        evaluation_context.account_cursor += 1 + self.accounts as usize;
+
+        assert_keys_equal(program.key, &self.program_id)?;
+
+        evaluation_context
+            .indices
+            .insert("program_guard_index", index);
+
+        // clears the return data so a previous value is not mistaken for the
+        // result of the external program
+        set_return_data(&[]);
+
+        self.invoke_hook(ctx, mint_args, index, ProgramGuardHook::Validate)?;
+
+        let code = match get_return_data() {
+            Some((program_id, data)) if program_id == self.program_id => {
+                u32::try_from_slice(&data).ok()
+            }
+            _ => None,
+        };
+
+        match code {
+            Some(0) => Ok(()),
+            Some(code) => {
+                msg!("External program rejected the mint with code {}", code);
+                err!(CandyGuardError::ProgramGuardRejected)
+            }
+            None => {
+                msg!("External program did not return a validation code");
+                err!(CandyGuardError::ProgramGuardRejected)
+            }
+        }
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["program_guard_index"];
+        self.invoke_hook(ctx, mint_args, index, ProgramGuardHook::PreActions)
+    }
+
+    fn post_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["program_guard_index"];
+        self.invoke_hook(ctx, mint_args, index, ProgramGuardHook::PostActions)
+    }
+}
@@ -23,11 +23,11 @@ impl Condition for RedeemedAmount {
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
         _mint_args: &[u8],
         _guard_set: &GuardSet,
-        _evaluation_context: &mut EvaluationContext,
+        evaluation_context: &mut EvaluationContext,
     ) -> Result<()> {
         let candy_machine = &ctx.accounts.candy_machine;
 
-        if candy_machine.items_redeemed >= self.maximum {
+        if candy_machine.items_redeemed + evaluation_context.quantity as u64 > self.maximum {
             return err!(CandyGuardError::MaximumRedeemedAmount);
         }
 
@@ -0,0 +1,326 @@
+use solana_program::{
+    ed25519_program, serialize_utils::read_u16, sysvar::instructions::load_instruction_at_checked,
+};
+
+use super::*;
+use crate::utils::{assert_keys_equal, cmp_pubkeys, create_or_allocate_account};
+
+/// Size of the signed message: payer (32) + candy machine (32) + expiry (8) + nonce (8).
+const MESSAGE_SIZE: usize = 80;
+
+/// Size of the signature offsets of an ed25519 instruction.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Value stored on the voucher nonce PDA once the voucher is redeemed.
+const NONCE_USED: u8 = 1;
+
+/// Guard that requires a voucher signed by a specified key. The voucher is an
+/// `Ed25519Program` signature verification instruction included in the mint
+/// transaction, where the signed message contains the payer, candy machine, expiry
+/// timestamp and nonce of the voucher:
+///
+/// `[payer pubkey (32), candy machine pubkey (32), expiry (i64 LE), nonce (u64 LE)]`
+///
+/// The nonce is consumed on the mint, so a voucher can only be used once.
+///
+/// List of accounts required:
+///
+///   0. `[writable]` Voucher nonce PDA (seeds `["voucher", signer pubkey, nonce,
+///                   candy guard pubkey, candy machine pubkey]`).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SignedVoucher {
+    pub signer: Pubkey,
+}
+
+/// Content of a voucher message.
+pub struct Voucher {
+    pub payer: Pubkey,
+    pub candy_machine: Pubkey,
+    pub expiry: i64,
+    pub nonce: u64,
+}
+
+impl SignedVoucher {
+    /// Finds the voucher signed by the guard signer for the payer and candy machine
+    /// in the ed25519 instructions of the transaction.
+    pub fn find_voucher(
+        &self,
+        instruction_sysvar_account: &AccountInfo,
+        payer: &Pubkey,
+        candy_machine: &Pubkey,
+    ) -> Result<Voucher> {
+        let num_instructions = {
+            let instruction_sysvar = instruction_sysvar_account.data.borrow();
+            read_u16(&mut 0, &instruction_sysvar).map_err(|_| ProgramError::InvalidAccountData)?
+        };
+
+        for index in 0..num_instructions {
+            let instruction =
+                load_instruction_at_checked(index as usize, instruction_sysvar_account)?;
+
+            if !cmp_pubkeys(&instruction.program_id, &ed25519_program::ID) {
+                continue;
+            }
+
+            if let Some(voucher) = self.parse_voucher(&instruction.data) {
+                if cmp_pubkeys(&voucher.payer, payer)
+                    && cmp_pubkeys(&voucher.candy_machine, candy_machine)
+                {
+                    return Ok(voucher);
+                }
+            }
+        }
+
+        err!(CandyGuardError::MissingVoucherSignature)
+    }
+
+    /// Parses the message of an ed25519 instruction with a single signature from
+    /// the guard signer. The signature, public key and message must be in the
+    /// instruction data.
+    fn parse_voucher(&self, data: &[u8]) -> Option<Voucher> {
+        // number of signatures (u8) + padding (u8)
+        if data.len() < 2 + SIGNATURE_OFFSETS_SIZE || data[0] != 1 {
+            return None;
+        }
+
+        let offsets = &data[2..2 + SIGNATURE_OFFSETS_SIZE];
+        let read = |offset: usize| u16::from_le_bytes([offsets[offset], offsets[offset + 1]]);
+
+        // signature, public key and message must be in the same instruction
+        if read(2) != u16::MAX || read(6) != u16::MAX || read(12) != u16::MAX {
+            return None;
+        }
+
+        let public_key_offset = read(4) as usize;
+        let message_offset = read(8) as usize;
+        let message_size = read(10) as usize;
+
+        let public_key = data.get(public_key_offset..public_key_offset + 32)?;
+
+        if message_size != MESSAGE_SIZE || public_key != self.signer.as_ref() {
+            return None;
+        }
+
+        let message = data.get(message_offset..message_offset + MESSAGE_SIZE)?;
+
+        Some(Voucher {
+            payer: Pubkey::new(&message[0..32]),
+            candy_machine: Pubkey::new(&message[32..64]),
+            expiry: i64::from_le_bytes(message[64..72].try_into().ok()?),
+            nonce: u64::from_le_bytes(message[72..80].try_into().ok()?),
+        })
+    }
+
+    /// Derives the voucher nonce PDA.
+    pub fn find_nonce_pda(
+        &self,
+        nonce: u64,
+        candy_guard: &Pubkey,
+        candy_machine: &Pubkey,
+    ) -> (Pubkey, u8) {
+        Pubkey::find_program_address(
+            &[
+                b"voucher".as_ref(),
+                self.signer.as_ref(),
+                &nonce.to_le_bytes(),
+                candy_guard.as_ref(),
+                candy_machine.as_ref(),
+            ],
+            &crate::ID,
+        )
+    }
+}
+
+impl Guard for SignedVoucher {
+    fn size() -> usize {
+        32 // signer
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 23
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+}
+
+impl Condition for SignedVoucher {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        evaluation_context.assert_single_item()?;
+
+        let nonce_pda = Self::get_account_info(ctx, evaluation_context.account_cursor)?;
+        evaluation_context
+            .indices
+            .insert("signed_voucher_index", evaluation_context.account_cursor);
+        evaluation_context.account_cursor += 1;
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        let voucher = self.find_voucher(
+            &ctx.accounts.instruction_sysvar_account,
+            ctx.accounts.payer.key,
+            candy_machine_key,
+        )?;
+
+        if Clock::get()?.unix_timestamp > voucher.expiry {
+            return err!(CandyGuardError::VoucherExpired);
+        }
+
+        let (pda, _) = self.find_nonce_pda(voucher.nonce, candy_guard_key, candy_machine_key);
+        assert_keys_equal(nonce_pda.key, &pda)?;
+
+        // the nonce PDA is created when the voucher is redeemed; lamports are not
+        // checked since anyone can fund the address
+        if cmp_pubkeys(nonce_pda.owner, &crate::ID)
+            && nonce_pda.try_borrow_data()?.first() == Some(&NONCE_USED)
+        {
+            return err!(CandyGuardError::VoucherAlreadyUsed);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let nonce_pda =
+            Self::get_account_info(ctx, evaluation_context.indices["signed_voucher_index"])?;
+
+        let candy_guard_key = &ctx.accounts.candy_guard.key();
+        let candy_machine_key = &ctx.accounts.candy_machine.key();
+
+        // the voucher has already been validated
+        let voucher = self.find_voucher(
+            &ctx.accounts.instruction_sysvar_account,
+            ctx.accounts.payer.key,
+            candy_machine_key,
+        )?;
+        let (pda, bump) = self.find_nonce_pda(voucher.nonce, candy_guard_key, candy_machine_key);
+
+        let signer = [
+            b"voucher".as_ref(),
+            self.signer.as_ref(),
+            &voucher.nonce.to_le_bytes(),
+            candy_guard_key.as_ref(),
+            candy_machine_key.as_ref(),
+            &[bump],
+        ];
+        assert_keys_equal(nonce_pda.key, &pda)?;
+
+        // marks the nonce as used
+        create_or_allocate_account(
+            nonce_pda,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            1,
+            &signer,
+        )?;
+        nonce_pda.try_borrow_mut_data()?[0] = NONCE_USED;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Data of an ed25519 instruction with a single signature, where the public key,
+    /// signature and message follow the signature offsets.
+    fn ed25519_data(signer: &Pubkey, message: &[u8], instruction_index: u16) -> Vec<u8> {
+        let public_key_offset = 2 + SIGNATURE_OFFSETS_SIZE as u16;
+        let signature_offset = public_key_offset + 32;
+        let message_offset = signature_offset + 64;
+
+        let mut data = vec![1, 0];
+        for value in [
+            signature_offset,
+            instruction_index,
+            public_key_offset,
+            instruction_index,
+            message_offset,
+            message.len() as u16,
+            instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(&[0; 64]);
+        data.extend_from_slice(message);
+
+        data
+    }
+
+    fn message(payer: &Pubkey, candy_machine: &Pubkey, expiry: i64, nonce: u64) -> Vec<u8> {
+        let mut message = vec![];
+        message.extend_from_slice(payer.as_ref());
+        message.extend_from_slice(candy_machine.as_ref());
+        message.extend_from_slice(&expiry.to_le_bytes());
+        message.extend_from_slice(&nonce.to_le_bytes());
+        message
+    }
+
+    #[test]
+    fn parse_voucher_message() {
+        let guard = SignedVoucher {
+            signer: Pubkey::new_unique(),
+        };
+        let payer = Pubkey::new_unique();
+        let candy_machine = Pubkey::new_unique();
+        let message = message(&payer, &candy_machine, 1_700_000_000, 42);
+
+        let voucher = guard
+            .parse_voucher(&ed25519_data(&guard.signer, &message, u16::MAX))
+            .unwrap();
+        assert_eq!(voucher.payer, payer);
+        assert_eq!(voucher.candy_machine, candy_machine);
+        assert_eq!(voucher.expiry, 1_700_000_000);
+        assert_eq!(voucher.nonce, 42);
+    }
+
+    #[test]
+    fn parse_voucher_rejects_invalid_data() {
+        let guard = SignedVoucher {
+            signer: Pubkey::new_unique(),
+        };
+        let message = message(&Pubkey::new_unique(), &Pubkey::new_unique(), 0, 0);
+
+        // signed by a different key
+        let data = ed25519_data(&Pubkey::new_unique(), &message, u16::MAX);
+        assert!(guard.parse_voucher(&data).is_none());
+
+        // data on a different instruction
+        let data = ed25519_data(&guard.signer, &message, 0);
+        assert!(guard.parse_voucher(&data).is_none());
+
+        // message with an unexpected size
+        let data = ed25519_data(&guard.signer, &message[..MESSAGE_SIZE - 8], u16::MAX);
+        assert!(guard.parse_voucher(&data).is_none());
+
+        // multiple signatures
+        let mut data = ed25519_data(&guard.signer, &message, u16::MAX);
+        data[0] = 2;
+        assert!(guard.parse_voucher(&data).is_none());
+
+        // truncated data
+        let data = ed25519_data(&guard.signer, &message, u16::MAX);
+        assert!(guard.parse_voucher(&data[..data.len() - 1]).is_none());
+        assert!(guard.parse_voucher(&data[..8]).is_none());
+    }
+}
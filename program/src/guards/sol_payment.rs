@@ -24,9 +24,17 @@ impl Guard for SolPayment {
     fn mask() -> u64 {
         0b1u64 << 1
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for SolPayment {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -45,10 +53,12 @@ impl Condition for SolPayment {
             .indices
             .insert("lamports_destination", index);
 
-        if ctx.accounts.payer.lamports() < self.lamports {
+        let price = evaluation_context.scale(self.lamports)?;
+
+        if ctx.accounts.payer.lamports() < price {
             msg!(
                 "Require {} lamports, accounts has {} lamports",
-                self.lamports,
+                price,
                 ctx.accounts.payer.lamports(),
             );
             return err!(CandyGuardError::NotEnoughSOL);
@@ -66,13 +76,10 @@ impl Condition for SolPayment {
     ) -> Result<()> {
         let destination =
             Self::get_account_info(ctx, evaluation_context.indices["lamports_destination"])?;
+        let price = evaluation_context.scale(self.lamports)?;
 
         invoke(
-            &system_instruction::transfer(
-                &ctx.accounts.payer.key(),
-                &destination.key(),
-                self.lamports,
-            ),
+            &system_instruction::transfer(&ctx.accounts.payer.key(), &destination.key(), price),
             &[
                 ctx.accounts.payer.to_account_info(),
                 destination.to_account_info(),
@@ -80,6 +87,8 @@ impl Condition for SolPayment {
             ],
         )?;
 
+        evaluation_context.charge(GuardType::SolPayment, None, price);
+
         Ok(())
     }
 }
@@ -0,0 +1,215 @@
+use super::*;
+
+use solana_program::{program::invoke, system_instruction};
+
+use crate::{errors::CandyGuardError, utils::assert_keys_equal};
+
+/// Maximum number of destinations.
+pub const MAXIMUM_DESTINATIONS: usize = 5;
+
+/// Total of basis points of the shares (100%).
+const TOTAL_BASIS_POINTS: u16 = 10_000;
+
+/// Guard that charges an amount in SOL (lamports) for the mint, splitting the
+/// payment between multiple destinations.
+///
+/// List of accounts required:
+///
+///   0..n. `[writable]` Account to receive each share of the funds (in the same
+///         order as the `destinations` list).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SolPaymentSplit {
+    pub lamports: u64,
+    pub destinations: Vec<SplitDestination>,
+}
+
+/// Destination of a share of the payment.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct SplitDestination {
+    /// Address to receive the share.
+    pub address: Pubkey,
+    /// Share of the payment in basis points (1/100 of a percent).
+    pub basis_points: u16,
+}
+
+impl SolPaymentSplit {
+    /// Returns the amount of lamports of each destination for the specified total.
+    /// Any remainder from the division of the shares is added to the first
+    /// destination, so that the total of lamports transferred is always the price.
+    pub fn shares(&self, lamports: u64) -> Result<Vec<u64>> {
+        let mut shares = Vec::with_capacity(self.destinations.len());
+
+        for destination in &self.destinations {
+            let share = (lamports as u128)
+                .checked_mul(destination.basis_points as u128)
+                .and_then(|amount| amount.checked_div(TOTAL_BASIS_POINTS as u128))
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+            shares.push(share as u64);
+        }
+
+        let remainder = lamports
+            .checked_sub(shares.iter().sum())
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        if let Some(first) = shares.first_mut() {
+            *first += remainder;
+        }
+
+        Ok(shares)
+    }
+}
+
+impl Guard for SolPaymentSplit {
+    fn size() -> usize {
+        8   // lamports
+        + 4 // u32 (number of destinations)
+        + MAXIMUM_DESTINATIONS * (32 + 2) // max list of destinations (address + basis points)
+    }
+
+    fn mask() -> u64 {
+        0b1u64 << 20
+    }
+
+    fn uses_accounts() -> bool {
+        true
+    }
+
+    fn save(&self, data: &mut [u8], offset: usize) -> Result<()> {
+        if self.destinations.is_empty() || self.destinations.len() > MAXIMUM_DESTINATIONS {
+            return err!(CandyGuardError::InvalidNumberOfDestinations);
+        }
+
+        let total = self
+            .destinations
+            .iter()
+            .map(|destination| destination.basis_points as u32)
+            .sum::<u32>();
+
+        if total != TOTAL_BASIS_POINTS as u32 {
+            return err!(CandyGuardError::InvalidSplitBasisPoints);
+        }
+
+        let mut result = Vec::with_capacity(Self::size());
+        self.serialize(&mut result)?;
+
+        data[offset..(result.len() + offset)].copy_from_slice(&result[..]);
+
+        Ok(())
+    }
+}
+
+impl Condition for SolPaymentSplit {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(self.destinations.len())
+    }
+
+    fn validate<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.account_cursor;
+        // validates that we received all required accounts
+        for (offset, destination) in self.destinations.iter().enumerate() {
+            let account = Self::get_account_info(ctx, index + offset)?;
+            // validates the account information
+            assert_keys_equal(account.key, &destination.address)?;
+        }
+        evaluation_context.account_cursor += self.destinations.len();
+
+        evaluation_context
+            .indices
+            .insert("sol_payment_split_index", index);
+
+        let price = evaluation_context.scale(self.lamports)?;
+
+        if ctx.accounts.payer.lamports() < price {
+            msg!(
+                "Require {} lamports, accounts has {} lamports",
+                price,
+                ctx.accounts.payer.lamports(),
+            );
+            return err!(CandyGuardError::NotEnoughSOL);
+        }
+
+        Ok(())
+    }
+
+    fn pre_actions<'info>(
+        &self,
+        ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+        _mint_args: &[u8],
+        _guard_set: &GuardSet,
+        evaluation_context: &mut EvaluationContext,
+    ) -> Result<()> {
+        let index = evaluation_context.indices["sol_payment_split_index"];
+        let price = evaluation_context.scale(self.lamports)?;
+
+        for (offset, share) in self.shares(price)?.into_iter().enumerate() {
+            // the accounts have already been validated
+            let destination = Self::get_account_info(ctx, index + offset)?;
+
+            if share > 0 {
+                invoke(
+                    &system_instruction::transfer(
+                        &ctx.accounts.payer.key(),
+                        &destination.key(),
+                        share,
+                    ),
+                    &[
+                        ctx.accounts.payer.to_account_info(),
+                        destination.to_account_info(),
+                        ctx.accounts.system_program.to_account_info(),
+                    ],
+                )?;
+            }
+        }
+
+        evaluation_context.charge(GuardType::SolPaymentSplit, None, price);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(lamports: u64, basis_points: &[u16]) -> SolPaymentSplit {
+        SolPaymentSplit {
+            lamports,
+            destinations: basis_points
+                .iter()
+                .map(|basis_points| SplitDestination {
+                    address: Pubkey::new_unique(),
+                    basis_points: *basis_points,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn shares_add_up_to_the_price() {
+        let guard = split(1_000_000_000, &[5_000, 3_000, 2_000]);
+        assert_eq!(
+            guard.shares(guard.lamports).unwrap(),
+            vec![500_000_000, 300_000_000, 200_000_000]
+        );
+
+        // the remainder of the division goes to the first destination
+        let guard = split(10, &[3_333, 3_333, 3_334]);
+        assert_eq!(guard.shares(guard.lamports).unwrap(), vec![4, 3, 3]);
+
+        let guard = split(1, &[5_000, 5_000]);
+        assert_eq!(guard.shares(guard.lamports).unwrap(), vec![1, 0]);
+
+        let guard = split(u64::MAX, &[1, 9_999]);
+        let shares = guard.shares(guard.lamports).unwrap();
+        assert_eq!(
+            shares.iter().map(|share| *share as u128).sum::<u128>(),
+            u64::MAX as u128
+        );
+    }
+}
@@ -19,9 +19,17 @@ impl Guard for ThirdPartySigner {
     fn mask() -> u64 {
         0b1u64 << 4
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for ThirdPartySigner {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -3,12 +3,13 @@ use super::*;
 use crate::utils::*;
 
 /// Guard that requires addresses that hold an amount of a specified spl-token
-/// and burns them.
+/// and burns them. Both spl-token and Token-2022 mints are supported.
 ///
 /// List of accounts required:
 ///
 ///   0. `[writable]` Token account holding the required amount.
 ///   1. `[writable]` Token mint account.
+///   2. `[]` Token program of the mint (spl-token or Token-2022).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TokenBurn {
     pub amount: u64,
@@ -24,9 +25,17 @@ impl Guard for TokenBurn {
     fn mask() -> u64 {
         0b1u64 << 15
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for TokenBurn {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(3)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -42,13 +51,16 @@ impl Condition for TokenBurn {
 
         let account = assert_is_ata(token_gate_account, &ctx.accounts.payer.key(), &self.mint)?;
 
-        if account.amount >= self.amount {
+        if account.amount >= evaluation_context.scale(self.amount)? {
             let token_gate_mint = Self::get_account_info(ctx, token_gate_index + 1)?;
-            // consumes the remaning account
-            evaluation_context.account_cursor += 1;
+            let token_program = Self::get_account_info(ctx, token_gate_index + 2)?;
+            // consumes the remaning accounts
+            evaluation_context.account_cursor += 2;
 
             // is the mint account the one expected?
             assert_keys_equal(&token_gate_mint.key(), &self.mint)?;
+            assert_is_mint(token_gate_mint)?;
+            assert_token_program(token_program, token_gate_mint)?;
         } else {
             return err!(CandyGuardError::NotEnoughTokens);
         }
@@ -71,16 +83,23 @@ impl Condition for TokenBurn {
         // the accounts have already being validated
         let token_gate_account = Self::get_account_info(ctx, token_gate_index)?;
         let token_gate_mint = Self::get_account_info(ctx, token_gate_index + 1)?;
+        let token_program = Self::get_account_info(ctx, token_gate_index + 2)?;
+
+        let decimals = assert_is_mint(token_gate_mint)?.decimals;
+        let amount = evaluation_context.scale(self.amount)?;
 
         spl_token_burn(TokenBurnParams {
             mint: token_gate_mint.to_account_info(),
             source: token_gate_account.to_account_info(),
-            amount: self.amount,
+            amount,
+            decimals,
             authority: ctx.accounts.payer.to_account_info(),
             authority_signer_seeds: None,
-            token_program: ctx.accounts.token_program.to_account_info(),
+            token_program: token_program.to_account_info(),
         })?;
 
+        evaluation_context.charge(GuardType::TokenBurn, Some(self.mint), amount);
+
         Ok(())
     }
 }
@@ -3,6 +3,7 @@ use super::*;
 use crate::utils::*;
 
 /// Guard that restricts access to addresses that hold the specified spl-token.
+/// Both spl-token and Token-2022 mints are supported.
 ///
 /// List of accounts required:
 ///
@@ -22,9 +23,17 @@ impl Guard for TokenGate {
     fn mask() -> u64 {
         0b1u64 << 5
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for TokenGate {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(1)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -2,15 +2,23 @@ use super::*;
 
 use crate::{
     errors::CandyGuardError,
-    utils::{assert_is_ata, assert_keys_equal, spl_token_transfer, TokenTransferParams},
+    utils::{
+        assert_is_ata, assert_is_mint, assert_keys_equal, assert_token_program, get_pre_fee_amount,
+        spl_token_transfer, TokenTransferParams,
+    },
 };
 
 /// Guard that charges an amount in a specified spl-token as payment for the mint.
+/// Both spl-token and Token-2022 mints are supported; when the mint has a transfer
+/// fee, the fee is added to the amount charged so that the destination receives
+/// the configured amount.
 ///
 /// List of accounts required:
 ///
 ///   0. `[writable]` Token account holding the required amount.
 ///   1. `[writable]` Address of the ATA to receive the tokens.
+///   2. `[]` Token mint account.
+///   3. `[]` Token program of the mint (spl-token or Token-2022).
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct TokenPayment {
     pub amount: u64,
@@ -28,9 +36,17 @@ impl Guard for TokenPayment {
     fn mask() -> u64 {
         0b1u64 << 2
     }
+
+    fn uses_accounts() -> bool {
+        true
+    }
 }
 
 impl Condition for TokenPayment {
+    fn accounts_count(&self) -> Option<usize> {
+        Some(4)
+    }
+
     fn validate<'info>(
         &self,
         ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -42,9 +58,14 @@ impl Condition for TokenPayment {
         let token_account_index = evaluation_context.account_cursor;
         let token_account_info = Self::get_account_info(ctx, token_account_index)?;
         let destination_ata = Self::get_account_info(ctx, token_account_index + 1)?;
-        evaluation_context.account_cursor += 2;
+        let token_mint = Self::get_account_info(ctx, token_account_index + 2)?;
+        let token_program = Self::get_account_info(ctx, token_account_index + 3)?;
+        evaluation_context.account_cursor += 4;
 
         assert_keys_equal(destination_ata.key, &self.destination_ata)?;
+        assert_keys_equal(token_mint.key, &self.token_mint)?;
+        assert_is_mint(token_mint)?;
+        assert_token_program(token_program, token_mint)?;
 
         let token_account = assert_is_ata(
             token_account_info,
@@ -52,7 +73,9 @@ impl Condition for TokenPayment {
             &self.token_mint,
         )?;
 
-        if token_account.amount < self.amount {
+        let amount = evaluation_context.scale(self.amount)?;
+
+        if token_account.amount < get_pre_fee_amount(token_mint, amount)? {
             return err!(CandyGuardError::NotEnoughTokens);
         }
 
@@ -74,16 +97,26 @@ impl Condition for TokenPayment {
         // the accounts have already been validated
         let token_account_info = Self::get_account_info(ctx, index)?;
         let destination_ata = Self::get_account_info(ctx, index + 1)?;
+        let token_mint = Self::get_account_info(ctx, index + 2)?;
+        let token_program = Self::get_account_info(ctx, index + 3)?;
+
+        let decimals = assert_is_mint(token_mint)?.decimals;
+        // amount transferred, including the transfer fee of the mint (if any)
+        let amount = get_pre_fee_amount(token_mint, evaluation_context.scale(self.amount)?)?;
 
         spl_token_transfer(TokenTransferParams {
             source: token_account_info.to_account_info(),
             destination: destination_ata.to_account_info(),
+            mint: token_mint.to_account_info(),
             authority: ctx.accounts.payer.to_account_info(),
             authority_signer_seeds: &[],
-            token_program: ctx.accounts.token_program.to_account_info(),
-            amount: self.amount,
+            token_program: token_program.to_account_info(),
+            amount,
+            decimals,
         })?;
 
+        evaluation_context.charge(GuardType::TokenPayment, Some(self.token_mint), amount);
+
         Ok(())
     }
 }
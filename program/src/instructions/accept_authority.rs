@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    events::AcceptAuthorityEvent,
+    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+};
+
+pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let new_authority = ctx.accounts.new_authority.key();
+
+    // validates and clears the pending authority
+    {
+        let mut account_data = account_info.data.borrow_mut();
+        let pending_authority = CandyGuardData::pending_authority(&account_data[DATA_OFFSET..])?;
+
+        if pending_authority != Some(new_authority) {
+            return err!(CandyGuardError::InvalidPendingAuthority);
+        }
+
+        CandyGuardData::set_pending_authority(&mut account_data[DATA_OFFSET..], None)?;
+    }
+
+    let candy_guard = &mut ctx.accounts.candy_guard;
+    let previous_authority = candy_guard.authority;
+    candy_guard.authority = new_authority;
+
+    emit!(AcceptAuthorityEvent {
+        candy_guard: candy_guard.key(),
+        previous_authority,
+        authority: new_authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    // pending authority of the candy guard
+    pub new_authority: Signer<'info>,
+}
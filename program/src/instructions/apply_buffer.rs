@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE;
+
+use crate::{
+    errors::CandyGuardError,
+    instructions::update::save_update,
+    state::{
+        CandyGuard, CandyGuardData, ConfigBuffer, DelegateRecord, BUFFER_DATA_OFFSET, BUFFER_SEED,
+        DATA_OFFSET, SEED,
+    },
+    utils::{assert_keys_equal, resize_account},
+};
+
+pub fn apply_buffer(ctx: Context<ApplyBuffer>) -> Result<()> {
+    let buffer_info = ctx.accounts.buffer.to_account_info();
+    // validates the buffer account
+    let buffer = Account::<ConfigBuffer>::try_from(&buffer_info)?;
+
+    if buffer.candy_guard != ctx.accounts.candy_guard.key() {
+        return err!(CandyGuardError::PublicKeyMismatch);
+    }
+
+    // a buffer staged by a previous authority cannot be applied
+    if buffer.authority != ctx.accounts.candy_guard.authority {
+        msg!("Buffer authority is not the candy guard authority");
+        return err!(CandyGuardError::PublicKeyMismatch);
+    }
+
+    assert_keys_equal(ctx.accounts.buffer_authority.key, &buffer.authority)?;
+
+    let data = {
+        let buffer_data = buffer_info.data.borrow();
+        CandyGuardData::deserialize(&mut &buffer_data[BUFFER_DATA_OFFSET..])
+            .map_err(|_| error!(CandyGuardError::DeserializationError))?
+    };
+
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+    let limit = account_info.data_len() + MAX_PERMITTED_DATA_INCREASE;
+
+    // the account can only grow by MAX_PERMITTED_DATA_INCREASE bytes on each
+    // instruction, so large configurations require extending the account first
+    if data.size() > limit {
+        // a delegate can only extend the account for a configuration in the scope
+        // of its role
+        if let Some(role) = DelegateRecord::authorize(
+            &ctx.accounts.candy_guard,
+            &ctx.accounts.authority,
+            ctx.remaining_accounts,
+        )? {
+            let account_data = account_info.data.borrow();
+            let current = CandyGuardData::load(&account_data[DATA_OFFSET..])?;
+            role.assert_update(&current, &data)?;
+        }
+
+        resize_account(
+            &account_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            limit,
+        )?;
+
+        msg!(
+            "Candy guard account extended to {} bytes ({} bytes required)",
+            limit,
+            data.size()
+        );
+
+        return Ok(());
+    }
+
+    save_update(
+        &ctx.accounts.candy_guard,
+        &ctx.accounts.authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        &data,
+    )?;
+
+    // closes the buffer, transferring the rent lamports to the buffer authority
+    let buffer_authority = ctx.accounts.buffer_authority.to_account_info();
+
+    **buffer_authority.lamports.borrow_mut() = buffer_authority
+        .lamports()
+        .checked_add(buffer_info.lamports())
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+    **buffer_info.lamports.borrow_mut() = 0;
+
+    let mut buffer_data = buffer_info.try_borrow_mut_data()?;
+    buffer_data.fill(0);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApplyBuffer<'info> {
+    #[account(
+        mut,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    /// CHECK: account checked in the instruction (it is closed manually, since it is
+    /// only closed once the configuration is applied)
+    #[account(
+        mut,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub buffer: UncheckedAccount<'info>,
+    /// CHECK: account checked in the instruction (receives the rent lamports of the
+    /// buffer when it is closed)
+    #[account(mut)]
+    pub buffer_authority: UncheckedAccount<'info>,
+    // candy guard authority or delegate (the delegate record is expected as the
+    // first remaining account)
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CandyGuard, ConfigBuffer, BUFFER_SEED, SEED};
+
+pub fn close_buffer(_ctx: Context<CloseBuffer>) -> Result<()> {
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseBuffer<'info> {
+    #[account(
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        close = buffer_authority,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump = buffer.bump
+    )]
+    pub buffer: Account<'info, ConfigBuffer>,
+    // buffer authority or candy guard authority (the candy guard authority can close
+    // a buffer staged by a previous authority)
+    #[account(
+        constraint = authority.key() == buffer.authority
+            || authority.key() == candy_guard.authority
+    )]
+    pub authority: Signer<'info>,
+    /// CHECK: account constraints checked in account trait
+    #[account(mut, address = buffer.authority)]
+    pub buffer_authority: UncheckedAccount<'info>,
+}
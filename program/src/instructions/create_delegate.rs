@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::CreateDelegateEvent,
+    state::{CandyGuard, DelegateRecord, DelegateRole, DELEGATE_SEED, SEED},
+};
+
+pub fn create_delegate(ctx: Context<CreateDelegate>, role: DelegateRole) -> Result<()> {
+    role.validate()?;
+
+    let delegate_record = &mut ctx.accounts.delegate_record;
+    delegate_record.candy_guard = ctx.accounts.candy_guard.key();
+    delegate_record.delegate = ctx.accounts.delegate.key();
+    delegate_record.authority = ctx.accounts.authority.key();
+    delegate_record.role = role.clone();
+    delegate_record.bump = *ctx.bumps.get("delegate_record").unwrap();
+
+    emit!(CreateDelegateEvent {
+        candy_guard: delegate_record.candy_guard,
+        delegate: delegate_record.delegate,
+        role,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(role: DelegateRole)]
+pub struct CreateDelegate<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    pub authority: Signer<'info>,
+    /// CHECK: delegate can be any account and is not written to or read
+    pub delegate: UncheckedAccount<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = DelegateRecord::size(&role),
+        seeds = [
+            DELEGATE_SEED,
+            candy_guard.key().as_ref(),
+            delegate.key().as_ref(),
+            &[role.seed()]
+        ],
+        bump
+    )]
+    pub delegate_record: Account<'info, DelegateRecord>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use anchor_lang::prelude::*;
+use solana_program::program::set_return_data;
+
+use crate::{
+    guards::{Condition, EvaluationContext},
+    instructions::mint::{validate, Mint},
+    state::{CandyGuardData, Features, GuardType, DATA_OFFSET},
+};
+
+/// Error of a guard that failed the evaluation.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct GuardError {
+    /// Type of the guard.
+    pub guard: GuardType,
+    /// Error code (program error value) of the guard validation.
+    pub code: u64,
+}
+
+/// Result of the evaluation of the guards, returned through the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct EvaluationResult {
+    /// Error code of the transaction validation (e.g., the candy machine is empty).
+    pub transaction_error: Option<u64>,
+    /// Feature bitmap of the guards evaluated.
+    pub evaluated: Vec<u64>,
+    /// Feature bitmap of the guards that failed.
+    pub failed: Vec<u64>,
+    /// Errors of the guards that failed, in the order they were evaluated.
+    pub errors: Vec<GuardError>,
+    /// Feature bitmap of the guards not evaluated, since their accounts could not be
+    /// located after a guard failed.
+    pub skipped: Vec<u64>,
+}
+
+pub fn evaluate<'info>(
+    ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
+    mint_args: Vec<u8>,
+    label: Option<String>,
+) -> Result<()> {
+    let candy_guard = &ctx.accounts.candy_guard;
+    let account_info = &candy_guard.to_account_info();
+    let account_data = account_info.data.borrow();
+    // loads the active guard set
+    let guard_set = CandyGuardData::active_set(&account_data[DATA_OFFSET..], label)?;
+
+    // evaluation context for this transaction
+    let mut evaluation_context = EvaluationContext {
+        account_cursor: 0,
+        args_cursor: 0,
+        indices: BTreeMap::new(),
+        quantity: 1,
+        charges: vec![],
+    };
+
+    let transaction_error = validate(&ctx, 1)
+        .err()
+        .map(|error| u64::from(ProgramError::from(error)));
+
+    // guards combined by the combinator guard are evaluated by the combinator
+    let combined = guard_set
+        .combinator
+        .as_ref()
+        .map_or(0, |combinator| combinator.members());
+
+    let result = EvaluationResult {
+        transaction_error,
+        ..evaluate_guards(
+            guard_set.enabled_guards(),
+            combined,
+            &mut evaluation_context,
+            |_, condition, evaluation_context| {
+                condition.validate(&ctx, &mint_args, &guard_set, evaluation_context)
+            },
+        )
+    };
+
+    set_return_data(&result.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Validates all enabled guards (except the ones in the `combined` mask) without stopping
+/// at the first error.
+///
+/// A guard might fail before consuming all of its accounts, so the account cursor is
+/// moved to the end of the accounts of a failed guard. When the number of accounts of a
+/// failed guard is not known, the accounts of the following guards cannot be located and
+/// the guards that use accounts are skipped.
+fn evaluate_guards<'a, F>(
+    guards: Vec<(GuardType, Features, &dyn Condition)>,
+    combined: u64,
+    evaluation_context: &mut EvaluationContext<'a>,
+    mut validate: F,
+) -> EvaluationResult
+where
+    F: FnMut(GuardType, &dyn Condition, &mut EvaluationContext<'a>) -> Result<()>,
+{
+    let mut evaluated = Features::default();
+    let mut failed = Features::default();
+    let mut skipped = Features::default();
+    let mut errors = vec![];
+    let mut aligned = true;
+
+    for (guard, feature, condition) in guards {
+        if feature.contains(0, combined) {
+            continue;
+        }
+
+        let accounts_count = condition.accounts_count();
+
+        if !aligned && accounts_count != Some(0) {
+            msg!("Guard {:?} skipped", guard);
+            skipped.insert(&feature);
+            continue;
+        }
+
+        evaluated.insert(&feature);
+        let account_cursor = evaluation_context.account_cursor;
+
+        if let Err(error) = validate(guard, condition, evaluation_context) {
+            msg!("Guard {:?} failed: {:?}", guard, error);
+            failed.insert(&feature);
+            errors.push(GuardError {
+                guard,
+                code: u64::from(ProgramError::from(error)),
+            });
+
+            if let Some(count) = accounts_count {
+                evaluation_context.account_cursor = account_cursor + count;
+            } else {
+                aligned = false;
+            }
+        }
+    }
+
+    EvaluationResult {
+        transaction_error: None,
+        evaluated: evaluated.words(),
+        failed: failed.words(),
+        errors,
+        skipped: skipped.words(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        errors::CandyGuardError,
+        guards::{Combinator, Guard, NftGate, NftPayment, ProgramGuard, StartDate},
+        state::GuardSet,
+    };
+
+    fn evaluation_context<'a>() -> EvaluationContext<'a> {
+        EvaluationContext {
+            account_cursor: 0,
+            args_cursor: 0,
+            indices: BTreeMap::new(),
+            quantity: 1,
+            charges: vec![],
+        }
+    }
+
+    #[test]
+    fn failed_guard_keeps_accounts_aligned() {
+        let guard_set = GuardSet {
+            nft_payment: Some(NftPayment {
+                required_collection: Pubkey::new_unique(),
+                destination: Pubkey::new_unique(),
+            }),
+            nft_gate: Some(NftGate {
+                required_collection: Pubkey::new_unique(),
+            }),
+            ..GuardSet::default()
+        };
+        let mut evaluation_context = evaluation_context();
+        let mut nft_gate_cursor = None;
+
+        let result = evaluate_guards(
+            guard_set.enabled_guards(),
+            0,
+            &mut evaluation_context,
+            |guard, _, evaluation_context| match guard {
+                // fails on the metadata checks, before consuming the destination accounts
+                GuardType::NftPayment => {
+                    evaluation_context.account_cursor += 3;
+                    err!(CandyGuardError::InvalidNftCollection)
+                }
+                _ => {
+                    nft_gate_cursor = Some(evaluation_context.account_cursor);
+                    evaluation_context.account_cursor += 2;
+                    Ok(())
+                }
+            },
+        );
+
+        // the nft gate accounts follow the 6 accounts of the nft payment
+        assert_eq!(nft_gate_cursor, Some(6));
+        assert_eq!(evaluation_context.account_cursor, 8);
+        assert_eq!(result.failed, vec![NftPayment::mask()]);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(result.skipped, vec![0]);
+    }
+
+    #[test]
+    fn guards_with_accounts_skipped_when_misaligned() {
+        let guard_set = GuardSet {
+            start_date: Some(StartDate { date: 0 }),
+            combinator: Some(Combinator {
+                branches: vec![StartDate::mask()],
+                negated: 0,
+            }),
+            program_guard: Some(ProgramGuard {
+                program_id: Pubkey::new_unique(),
+                accounts: 0,
+                config: vec![],
+            }),
+            ..GuardSet::default()
+        };
+        let mut evaluation_context = evaluation_context();
+
+        let result = evaluate_guards(
+            guard_set.enabled_guards(),
+            StartDate::mask(),
+            &mut evaluation_context,
+            |guard, _, _| match guard {
+                GuardType::Combinator => err!(CandyGuardError::CombinatorConditionNotMet),
+                _ => panic!("Guard {:?} should not be evaluated", guard),
+            },
+        );
+
+        assert_eq!(result.evaluated, vec![Combinator::mask()]);
+        assert_eq!(result.skipped, vec![ProgramGuard::mask()]);
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+use crate::state::{CandyGuard, ConfigBuffer, BUFFER_DATA_OFFSET, BUFFER_SEED, SEED};
+
+pub fn initialize_buffer(ctx: Context<InitializeBuffer>) -> Result<()> {
+    let buffer = &mut ctx.accounts.buffer;
+    buffer.candy_guard = ctx.accounts.candy_guard.key();
+    buffer.authority = ctx.accounts.authority.key();
+    buffer.bump = *ctx.bumps.get("buffer").unwrap();
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeBuffer<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = BUFFER_DATA_OFFSET,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump
+    )]
+    pub buffer: Account<'info, ConfigBuffer>,
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{CandyGuard, CandyGuardData, DATA_OFFSET, LAYOUT_VERSION, SEED},
+    utils::resize_account,
+};
+
+pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+
+    // loads the guards data using the layout of the account
+    let data = {
+        let account_data = account_info.data.borrow();
+        let version = CandyGuardData::layout_version(&account_data[DATA_OFFSET..])?;
+
+        if version == LAYOUT_VERSION {
+            msg!("Candy guard layout is already at version {}", version);
+            return Ok(());
+        }
+
+        msg!(
+            "Migrating candy guard layout from version {} to {}",
+            version,
+            LAYOUT_VERSION
+        );
+
+        CandyGuardData::load(&account_data[DATA_OFFSET..])?
+    };
+
+    // resizes the account to fit the current layout
+    resize_account(
+        &account_info,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        data.size(),
+    )?;
+
+    // rewrites the guards information using the current layout
+    let mut account_data = account_info.data.borrow_mut();
+    data.save(&mut account_data[DATA_OFFSET..])?;
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
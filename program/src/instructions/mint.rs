@@ -5,71 +5,220 @@ use anchor_lang::{prelude::*, solana_program::sysvar};
 use mpl_candy_machine_core::CandyMachine;
 
 use crate::{
-    guards::{CandyGuardError, EvaluationContext},
+    events::MintEvent,
+    guards::{CandyGuardError, Condition, EvaluationContext},
     state::{CandyGuard, CandyGuardData, GuardSet, DATA_OFFSET, SEED},
     utils::cmp_pubkeys,
 };
 
+// Number of accounts of each additional item of a multiple mint:
+//   nft mint, nft metadata and nft master edition
+pub const ITEM_ACCOUNTS: usize = 3;
+
+// Marker at the start of the `mint_args` to opt in to the automatic selection of the
+// group when no label is specified.
+pub const AUTO_SELECT_MARKER: u8 = u8::MAX;
+
 pub fn mint<'info>(
     ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
     mint_args: Vec<u8>,
     label: Option<String>,
 ) -> Result<()> {
+    mint_multiple(ctx, mint_args, label, 1)
+}
+
+/// Mints `quantity` items in the same transaction. The accounts of the first item are
+/// the NFT accounts of the mint instruction, while the accounts of each additional item
+/// (nft mint, nft metadata and nft master edition) are the last remaining accounts, after
+/// the accounts of the guards.
+pub fn mint_multiple<'info>(
+    ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
+    mint_args: Vec<u8>,
+    label: Option<String>,
+    quantity: u8,
+) -> Result<()> {
+    if quantity == 0 {
+        return err!(CandyGuardError::InvalidQuantity);
+    }
+
+    // accounts of the additional items
+    let item_accounts = (quantity as usize - 1) * ITEM_ACCOUNTS;
+
     let candy_guard = &ctx.accounts.candy_guard;
     let account_info = &candy_guard.to_account_info();
     let account_data = account_info.data.borrow();
-    // loads the active guard set
-    let guard_set = match CandyGuardData::active_set(&account_data[DATA_OFFSET..], label) {
-        Ok(guard_set) => guard_set,
-        Err(error) => {
-            // load the default guard set to look for the bot_tax since errors only occur
-            // when trying to load guard set groups
-            let (default, _) = GuardSet::from_data(&account_data[DATA_OFFSET..])?;
-            return process_error(&ctx, &default, error);
-        }
-    };
-
-    let conditions = guard_set.enabled_conditions();
+    let data = &account_data[DATA_OFFSET..];
 
     // evaluation context for this transaction
     let mut evaluation_context = EvaluationContext {
         account_cursor: 0,
         args_cursor: 0,
         indices: BTreeMap::new(),
+        quantity,
+        charges: vec![],
     };
 
-    // validates the required transaction data
+    // loads the active guard set and validates the guards (any error at this point
+    // is subject to bot tax)
+    let (guard_set, label, args_offset) = match CandyGuardData::active_set(data, label.clone()) {
+        Ok(guard_set) => {
+            // validates the required transaction data and enabled guards
+            if let Err(error) = validate(&ctx, quantity).and_then(|_| {
+                validate_conditions(&ctx, &mint_args, &guard_set, &mut evaluation_context)
+            }) {
+                return process_error(&ctx, &guard_set, error);
+            }
 
-    if let Err(error) = validate(&ctx) {
-        return process_error(&ctx, &guard_set, error);
-    }
+            (guard_set, label, 0)
+        }
+        Err(error) => {
+            // load the default guard set to look for the bot_tax since errors only occur
+            // when trying to load guard set groups
+            let default = CandyGuardData::default_set(data)?;
 
-    // validates enabled guards (any error at this point is subject to bot tax)
+            // when no label is specified, the group is selected automatically
+            let selection = if error == error!(CandyGuardError::RequiredGroupLabelNotFound) {
+                validate(&ctx, quantity)
+                    .and_then(|_| select_group(&ctx, &mint_args, data, &mut evaluation_context))
+            } else {
+                Err(error)
+            };
 
-    for condition in &conditions {
-        if let Err(error) =
-            condition.validate(&ctx, &mint_args, &guard_set, &mut evaluation_context)
-        {
-            return process_error(&ctx, &guard_set, error);
+            match selection {
+                Ok((guard_set, label, args_offset)) => (guard_set, Some(label), args_offset),
+                Err(error) => return process_error(&ctx, &default, error),
+            }
         }
+    };
+
+    // the accounts of the additional items cannot be used by the guards
+    if evaluation_context.account_cursor + item_accounts > ctx.remaining_accounts.len() {
+        return process_error(
+            &ctx,
+            &guard_set,
+            error!(CandyGuardError::MissingRemainingAccount),
+        );
     }
 
+    let conditions = conditions(&guard_set);
+    // the guards do not receive the group selection arguments
+    let mint_args = &mint_args[args_offset..];
+
     // after this point, errors might occur, which will cause the transaction to fail
     // no bot tax from this point since the actions must be reverted in case of an error
 
     for condition in &conditions {
-        condition.pre_actions(&ctx, &mint_args, &guard_set, &mut evaluation_context)?;
+        condition.pre_actions(&ctx, mint_args, &guard_set, &mut evaluation_context)?;
     }
 
-    cpi_mint(&ctx)?;
+    cpi_mint(
+        &ctx,
+        &ctx.accounts.nft_mint,
+        &ctx.accounts.nft_metadata,
+        &ctx.accounts.nft_master_edition,
+    )?;
+
+    let items = &ctx.remaining_accounts[ctx.remaining_accounts.len() - item_accounts..];
+
+    for item in items.chunks(ITEM_ACCOUNTS) {
+        cpi_mint(&ctx, &item[0], &item[1], &item[2])?;
+    }
 
     for condition in &conditions {
-        condition.post_actions(&ctx, &mint_args, &guard_set, &mut evaluation_context)?;
+        condition.post_actions(&ctx, mint_args, &guard_set, &mut evaluation_context)?;
     }
 
+    emit!(MintEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        candy_machine: ctx.accounts.candy_machine.key(),
+        payer: ctx.accounts.payer.key(),
+        label,
+        quantity,
+        charges: evaluation_context.charges,
+    });
+
     Ok(())
 }
 
+/// Returns the conditions of the guard set evaluated by the mint.
+fn conditions(guard_set: &GuardSet) -> Vec<&dyn Condition> {
+    // guards combined by the combinator guard are evaluated by the combinator
+    if let Some(combinator) = &guard_set.combinator {
+        guard_set.conditions(!combinator.members())
+    } else {
+        guard_set.enabled_conditions()
+    }
+}
+
+/// Validates the conditions of the guard set.
+fn validate_conditions<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    mint_args: &[u8],
+    guard_set: &GuardSet,
+    evaluation_context: &mut EvaluationContext,
+) -> Result<()> {
+    for condition in conditions(guard_set) {
+        condition.validate(ctx, mint_args, guard_set, evaluation_context)?;
+    }
+
+    Ok(())
+}
+
+/// Selects the first group (in the order they are stored) whose guards validate. The
+/// guards of each group are merged with the default guard set. Returns the guard set
+/// and label of the group selected, and the offset of the guard arguments in the
+/// `mint_args`.
+///
+/// The `mint_args` must start with the `AUTO_SELECT_MARKER` followed by the number of
+/// remaining accounts (`u8`) of each group; the remaining accounts are then split into
+/// consecutive sections, one for each group in the same order. A group is only eligible
+/// when its guards do not use more accounts than its section. The guards only receive
+/// the arguments following the number of accounts of the groups.
+fn select_group<'a, 'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    mint_args: &[u8],
+    data: &[u8],
+    evaluation_context: &mut EvaluationContext<'a>,
+) -> Result<(Box<GuardSet>, String, usize)> {
+    let groups = CandyGuardData::merged_groups(data)?;
+    let args_offset = 1 + groups.len();
+
+    // without the marker and the number of accounts of each group, a label is required
+    if mint_args.first() != Some(&AUTO_SELECT_MARKER) || mint_args.len() < args_offset {
+        return err!(CandyGuardError::RequiredGroupLabelNotFound);
+    }
+
+    let sections = &mint_args[1..args_offset];
+    let guard_args = &mint_args[args_offset..];
+    let mut account_cursor = 0;
+
+    for (group, section) in groups.into_iter().zip(sections) {
+        let section_end = account_cursor + *section as usize;
+        let mut group_context = EvaluationContext {
+            account_cursor,
+            args_cursor: 0,
+            indices: BTreeMap::new(),
+            quantity: evaluation_context.quantity,
+            charges: vec![],
+        };
+
+        match validate_conditions(ctx, guard_args, &group.guards, &mut group_context) {
+            Ok(()) if group_context.account_cursor <= section_end => {
+                msg!("Group {} selected", group.label);
+                *evaluation_context = group_context;
+
+                return Ok((Box::new(group.guards), group.label, args_offset));
+            }
+            Ok(()) => msg!("Group {} used accounts outside of its section", group.label),
+            Err(error) => msg!("Group {} failed: {:?}", group.label, error),
+        }
+
+        account_cursor = section_end;
+    }
+
+    err!(CandyGuardError::NoEligibleGroup)
+}
+
 // Handles errors + bot tax charge.
 fn process_error<'info>(
     ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -85,10 +234,13 @@ fn process_error<'info>(
 }
 
 /// Performs a validation of the transaction before executing the guards.
-fn validate<'info>(ctx: &Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()> {
+pub(crate) fn validate<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    quantity: u8,
+) -> Result<()> {
     let candy_machine = &ctx.accounts.candy_machine;
-    // are there items to be minted?
-    if candy_machine.items_redeemed >= candy_machine.data.items_available {
+    // are there enough items to be minted?
+    if candy_machine.items_redeemed + quantity as u64 > candy_machine.data.items_available {
         return err!(CandyGuardError::CandyMachineEmpty);
     }
 
@@ -109,7 +261,12 @@ fn validate<'info>(ctx: &Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()>
 }
 
 /// Send a mint transaction to the candy machine.
-fn cpi_mint<'info>(ctx: &Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()> {
+fn cpi_mint<'info>(
+    ctx: &Context<'_, '_, '_, 'info, Mint<'info>>,
+    nft_mint: &AccountInfo<'info>,
+    nft_metadata: &AccountInfo<'info>,
+    nft_master_edition: &AccountInfo<'info>,
+) -> Result<()> {
     let candy_guard = &ctx.accounts.candy_guard;
     // PDA signer for the transaction
     let seeds = [SEED, &candy_guard.base.to_bytes(), &[candy_guard.bump]];
@@ -122,10 +279,10 @@ fn cpi_mint<'info>(ctx: &Context<'_, '_, '_, 'info, Mint<'info>>) -> Result<()>
         authority_pda: ctx.accounts.candy_machine_authority_pda.to_account_info(),
         mint_authority: ctx.accounts.candy_guard.to_account_info(),
         payer: ctx.accounts.payer.to_account_info(),
-        nft_mint: ctx.accounts.nft_mint.to_account_info(),
+        nft_mint: nft_mint.to_account_info(),
         nft_mint_authority: ctx.accounts.nft_mint_authority.to_account_info(),
-        nft_metadata: ctx.accounts.nft_metadata.to_account_info(),
-        nft_master_edition: ctx.accounts.nft_master_edition.to_account_info(),
+        nft_metadata: nft_metadata.to_account_info(),
+        nft_master_edition: nft_master_edition.to_account_info(),
         collection_authority_record: ctx.accounts.collection_authority_record.to_account_info(),
         collection_mint: ctx.accounts.collection_mint.to_account_info(),
         collection_metadata: ctx.accounts.collection_metadata.to_account_info(),
@@ -1,15 +1,39 @@
+pub use accept_authority::*;
+pub use apply_buffer::*;
+pub use close_buffer::*;
+pub use create_delegate::*;
+pub use evaluate::*;
 pub use initialize::*;
+pub use initialize_buffer::*;
+pub use migrate::*;
 pub use mint::*;
+pub use modify::*;
+pub use quote::*;
+pub use revoke_delegate::*;
 pub use route::*;
+pub use set_authority::*;
 pub use unwrap::*;
 pub use update::*;
 pub use withdraw::*;
 pub use wrap::*;
+pub use write_buffer::*;
 
+pub mod accept_authority;
+pub mod apply_buffer;
+pub mod close_buffer;
+pub mod create_delegate;
+pub mod evaluate;
 pub mod initialize;
+pub mod initialize_buffer;
+pub mod migrate;
 pub mod mint;
+pub mod modify;
+pub mod quote;
+pub mod revoke_delegate;
 pub mod route;
+pub mod set_authority;
 pub mod unwrap;
 pub mod update;
 pub mod withdraw;
 pub mod wrap;
+pub mod write_buffer;
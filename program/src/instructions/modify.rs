@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::CandyGuardError,
+    instructions::update::save_update,
+    state::{
+        CandyGuard, CandyGuardData, Group, GuardSet, GuardType, DATA_OFFSET, MAX_LABEL_SIZE, SEED,
+    },
+};
+
+pub fn set_guards(ctx: Context<Modify>, label: Option<String>, guards: GuardSet) -> Result<()> {
+    modify(ctx, |data| {
+        data.guard_set_mut(label.as_deref())?.merge(guards);
+        Ok(())
+    })
+}
+
+pub fn clear_guard(ctx: Context<Modify>, label: Option<String>, guard: GuardType) -> Result<()> {
+    modify(ctx, |data| {
+        data.guard_set_mut(label.as_deref())?
+            .set_guard(&guard, &GuardSet::default());
+        Ok(())
+    })
+}
+
+pub fn add_group(ctx: Context<Modify>, label: String, guards: GuardSet) -> Result<()> {
+    modify(ctx, |data| {
+        assert_label(data, &label)?;
+        data.groups
+            .get_or_insert_with(Vec::new)
+            .push(Group { label, guards });
+        Ok(())
+    })
+}
+
+pub fn remove_group(ctx: Context<Modify>, label: String) -> Result<()> {
+    modify(ctx, |data| {
+        // validates that the group exists
+        let _ = data.group_mut(&label)?;
+
+        if let Some(groups) = &mut data.groups {
+            groups.retain(|group| group.label != label);
+
+            if groups.is_empty() {
+                data.groups = None;
+            }
+        }
+
+        Ok(())
+    })
+}
+
+pub fn rename_group(ctx: Context<Modify>, label: String, new_label: String) -> Result<()> {
+    modify(ctx, |data| {
+        assert_label(data, &new_label)?;
+        data.group_mut(&label)?.label = new_label;
+        Ok(())
+    })
+}
+
+/// Applies the modification to the current configuration and saves the result in
+/// the same way as the `update` instruction.
+fn modify<F>(ctx: Context<Modify>, apply: F) -> Result<()>
+where
+    F: FnOnce(&mut CandyGuardData) -> Result<()>,
+{
+    let mut data = {
+        let account_info = ctx.accounts.candy_guard.to_account_info();
+        let account_data = account_info.data.borrow();
+        CandyGuardData::load(&account_data[DATA_OFFSET..])?
+    };
+
+    apply(&mut data)?;
+
+    save_update(
+        &ctx.accounts.candy_guard,
+        &ctx.accounts.authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        &data,
+    )
+}
+
+/// Asserts that the label can be used by a new group.
+fn assert_label(data: &CandyGuardData, label: &str) -> Result<()> {
+    if label.len() > MAX_LABEL_SIZE {
+        return err!(CandyGuardError::LabelExceededLength);
+    }
+
+    if data.has_group(label) {
+        return err!(CandyGuardError::DuplicateGroupLabel);
+    }
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Modify<'info> {
+    #[account(
+        mut,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    // candy guard authority or delegate (the delegate record is expected as the
+    // first remaining account)
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
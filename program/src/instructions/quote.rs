@@ -0,0 +1,140 @@
+use anchor_lang::prelude::*;
+use mpl_candy_machine_core::CandyMachine;
+use solana_program::program::set_return_data;
+
+use crate::{
+    errors::CandyGuardError,
+    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+    utils::{assert_is_mint, cmp_pubkeys, get_pre_fee_amount},
+};
+
+/// Amount of a token charged by the mint.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct TokenAmount {
+    pub mint: Pubkey,
+    pub amount: u64,
+}
+
+/// Price of a mint, returned through the return data.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
+pub struct PriceQuote {
+    /// Amount of lamports charged (`SolPayment`, `FreezeSolPayment`, `SolPaymentSplit`,
+    /// `DutchAuction` and `BondingCurve` in SOL).
+    pub lamports: u64,
+    /// Amount of tokens charged (`TokenPayment`, `FreezeTokenPayment` and
+    /// `BondingCurve` in spl-token), including the Token-2022 transfer fee added
+    /// to the amount by `TokenPayment` and `BondingCurve`.
+    pub tokens: Vec<TokenAmount>,
+    /// Amount of tokens burned (`TokenBurn`).
+    pub token_burns: Vec<TokenAmount>,
+    /// Collection of the NFT charged (`NftPayment`).
+    pub nft_payment: Option<Pubkey>,
+    /// Collection of the NFT burned (`NftBurn`).
+    pub nft_burn: Option<Pubkey>,
+}
+
+pub fn quote(ctx: Context<Quote>, label: Option<String>) -> Result<()> {
+    let candy_guard = &ctx.accounts.candy_guard;
+    let account_info = &candy_guard.to_account_info();
+    let account_data = account_info.data.borrow();
+    // loads the active guard set
+    let guard_set = CandyGuardData::active_set(&account_data[DATA_OFFSET..], label)?;
+
+    let mut quote = PriceQuote::default();
+    let mut lamports: Vec<u64> = vec![];
+
+    if let Some(sol_payment) = &guard_set.sol_payment {
+        lamports.push(sol_payment.lamports);
+    }
+
+    if let Some(freeze_sol_payment) = &guard_set.freeze_sol_payment {
+        lamports.push(freeze_sol_payment.lamports);
+    }
+
+    if let Some(sol_payment_split) = &guard_set.sol_payment_split {
+        lamports.push(sol_payment_split.lamports);
+    }
+
+    if let Some(dutch_auction) = &guard_set.dutch_auction {
+        lamports.push(dutch_auction.price_at(Clock::get()?.unix_timestamp)?);
+    }
+
+    if let Some(token_payment) = &guard_set.token_payment {
+        quote.tokens.push(TokenAmount {
+            mint: token_payment.token_mint,
+            amount: charged_amount(
+                ctx.remaining_accounts,
+                &token_payment.token_mint,
+                token_payment.amount,
+            )?,
+        });
+    }
+
+    if let Some(freeze_token_payment) = &guard_set.freeze_token_payment {
+        quote.tokens.push(TokenAmount {
+            mint: freeze_token_payment.mint,
+            amount: freeze_token_payment.amount,
+        });
+    }
+
+    if let Some(bonding_curve) = &guard_set.bonding_curve {
+        let price = bonding_curve.price_at(ctx.accounts.candy_machine.items_redeemed)?;
+
+        if let Some(token_mint) = bonding_curve.token_mint {
+            quote.tokens.push(TokenAmount {
+                mint: token_mint,
+                amount: charged_amount(ctx.remaining_accounts, &token_mint, price)?,
+            });
+        } else {
+            lamports.push(price);
+        }
+    }
+
+    if let Some(token_burn) = &guard_set.token_burn {
+        quote.token_burns.push(TokenAmount {
+            mint: token_burn.mint,
+            amount: token_burn.amount,
+        });
+    }
+
+    if let Some(nft_payment) = &guard_set.nft_payment {
+        quote.nft_payment = Some(nft_payment.required_collection);
+    }
+
+    if let Some(nft_burn) = &guard_set.nft_burn {
+        quote.nft_burn = Some(nft_burn.required_collection);
+    }
+
+    quote.lamports = lamports
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+    set_return_data(&quote.try_to_vec()?);
+
+    Ok(())
+}
+
+/// Returns the amount charged to transfer `amount` tokens to the destination, which
+/// includes the transfer fee of Token-2022 mints. The mint account is expected in the
+/// remaining accounts.
+fn charged_amount(remaining_accounts: &[AccountInfo], mint: &Pubkey, amount: u64) -> Result<u64> {
+    let mint = remaining_accounts
+        .iter()
+        .find(|account| cmp_pubkeys(account.key, mint))
+        .ok_or(CandyGuardError::MissingRemainingAccount)?;
+    assert_is_mint(mint)?;
+
+    get_pre_fee_amount(mint, amount)
+}
+
+#[derive(Accounts)]
+pub struct Quote<'info> {
+    #[account(
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(constraint = candy_guard.key() == candy_machine.mint_authority)]
+    pub candy_machine: Box<Account<'info, CandyMachine>>,
+}
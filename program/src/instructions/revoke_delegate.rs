@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::RevokeDelegateEvent,
+    state::{CandyGuard, DelegateRecord, SEED},
+};
+
+pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+    let delegate_record = &ctx.accounts.delegate_record;
+
+    emit!(RevokeDelegateEvent {
+        candy_guard: delegate_record.candy_guard,
+        delegate: delegate_record.delegate,
+        role: delegate_record.role.clone(),
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(mut, close = authority, has_one = candy_guard)]
+    pub delegate_record: Account<'info, DelegateRecord>,
+}
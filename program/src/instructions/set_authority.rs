@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    events::SetAuthorityEvent,
+    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+};
+
+pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+    let account_info = ctx.accounts.candy_guard.to_account_info();
+
+    // stages the new authority; it only takes effect once it is accepted by the
+    // new authority (a `None` value clears the pending authority)
+    let mut account_data = account_info.data.borrow_mut();
+    CandyGuardData::set_pending_authority(&mut account_data[DATA_OFFSET..], new_authority)?;
+
+    emit!(SetAuthorityEvent {
+        candy_guard: account_info.key(),
+        authority: ctx.accounts.authority.key(),
+        pending_authority: new_authority,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAuthority<'info> {
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    pub authority: Signer<'info>,
+}
@@ -4,9 +4,20 @@ use mpl_candy_machine_core::{
     CandyMachine,
 };
 
-use crate::state::CandyGuard;
+use crate::{
+    events::UnwrapEvent,
+    state::{CandyGuard, DelegateRecord},
+};
 
 pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
+    if let Some(role) = DelegateRecord::authorize(
+        &ctx.accounts.candy_guard,
+        &ctx.accounts.authority,
+        ctx.remaining_accounts,
+    )? {
+        role.assert_wrap()?;
+    }
+
     let candy_machine_program = ctx.accounts.candy_machine_program.to_account_info();
     let candy_machine_authority = &ctx.accounts.candy_machine_authority;
 
@@ -19,17 +30,20 @@ pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
     // candy machine set_mint_authority CPI
     set_mint_authority(cpi_ctx)?;
 
+    emit!(UnwrapEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        candy_machine: ctx.accounts.candy_machine.key(),
+    });
+
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct Unwrap<'info> {
-    #[account(
-        has_one = authority,
-        constraint = candy_guard.key() == candy_machine.mint_authority
-    )]
+    #[account(constraint = candy_guard.key() == candy_machine.mint_authority)]
     pub candy_guard: Account<'info, CandyGuard>,
-    // candy guard authority
+    // candy guard authority or delegate (the delegate record is expected as the
+    // first remaining account)
     pub authority: Signer<'info>,
     #[account(
         mut,
@@ -1,76 +1,61 @@
 use anchor_lang::prelude::*;
-use solana_program::{
-    entrypoint::MAX_PERMITTED_DATA_INCREASE, program::invoke, system_instruction,
-};
 
 use crate::{
-    errors::CandyGuardError,
-    state::{CandyGuard, CandyGuardData, DATA_OFFSET, SEED},
+    events::UpdateEvent,
+    state::{CandyGuard, CandyGuardData, DelegateRecord, DATA_OFFSET, SEED},
+    utils::resize_account,
 };
 
 pub fn update(ctx: Context<Update>, data: CandyGuardData) -> Result<()> {
-    let account_info = ctx.accounts.candy_guard.to_account_info();
-
-    // check whether we need to grow or shrink the account size or not
-    if data.size() != account_info.data_len() {
-        // no risk of overflow here since the sizes will range from DATA_OFFSET to 10_000_000
-        let difference = data.size() as i64 - account_info.data_len() as i64;
-        let snapshot = account_info.lamports();
-
-        if difference > 0 {
-            if difference as usize > MAX_PERMITTED_DATA_INCREASE {
-                return err!(CandyGuardError::DataIncrementLimitExceeded);
-            }
-
-            let lamports_diff = Rent::get()?
-                .minimum_balance(data.size())
-                .checked_sub(snapshot)
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
-
-            msg!("Funding {} lamports for account realloc", lamports_diff);
-
-            invoke(
-                &system_instruction::transfer(
-                    ctx.accounts.payer.key,
-                    account_info.key,
-                    lamports_diff,
-                ),
-                &[
-                    ctx.accounts.payer.to_account_info(),
-                    account_info.clone(),
-                    ctx.accounts.system_program.to_account_info(),
-                ],
-            )?;
-        } else {
-            let lamports_diff = snapshot
-                .checked_sub(Rent::get()?.minimum_balance(data.size()))
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
-
-            msg!(
-                "Withdrawing {} lamports from account realloc",
-                lamports_diff
-            );
-
-            **account_info.lamports.borrow_mut() = snapshot - lamports_diff;
-            let payer = &ctx.accounts.payer;
+    save_update(
+        &ctx.accounts.candy_guard,
+        &ctx.accounts.authority,
+        &ctx.accounts.payer,
+        &ctx.accounts.system_program,
+        ctx.remaining_accounts,
+        &data,
+    )
+}
 
-            **payer.lamports.borrow_mut() = payer
-                .lamports()
-                .checked_add(lamports_diff)
-                .ok_or(CandyGuardError::NumericalOverflowError)?;
-        }
+/// Saves the updated configuration to the candy guard account, resizing the account
+/// if needed. The signer must be the authority or a delegate whose role allows the
+/// update.
+pub(crate) fn save_update<'info>(
+    candy_guard: &Account<'info, CandyGuard>,
+    authority: &Signer<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+    remaining_accounts: &[AccountInfo],
+    data: &CandyGuardData,
+) -> Result<()> {
+    let account_info = candy_guard.to_account_info();
 
-        msg!("Account realloc by {} bytes", difference);
-        // changes the account size to fit the size required by the guards
-        // this means that the size can grow or shrink
-        account_info.realloc(data.size(), false)?;
+    // a delegate can only update the guards in the scope of its role
+    if let Some(role) = DelegateRecord::authorize(candy_guard, authority, remaining_accounts)? {
+        let account_data = account_info.data.borrow();
+        let current = CandyGuardData::load(&account_data[DATA_OFFSET..])?;
+        role.assert_update(&current, data)?;
     }
 
+    // resizes the account to fit the updated configuration
+    resize_account(
+        &account_info,
+        &payer.to_account_info(),
+        &system_program.to_account_info(),
+        data.size(),
+    )?;
+
     // save the guards information to the account data and stores
     // the updated feature flag
     let mut account_data = account_info.data.borrow_mut();
     data.save(&mut account_data[DATA_OFFSET..])?;
 
+    emit!(UpdateEvent {
+        candy_guard: account_info.key(),
+        authority: authority.key(),
+        size: data.size() as u64,
+    });
+
     Ok(())
 }
 
@@ -79,13 +64,15 @@ pub fn update(ctx: Context<Update>, data: CandyGuardData) -> Result<()> {
 pub struct Update<'info> {
     #[account(
         mut,
-        has_one = authority,
         seeds = [SEED, candy_guard.base.key().as_ref()],
         bump = candy_guard.bump
     )]
     pub candy_guard: Account<'info, CandyGuard>,
+    // candy guard authority or delegate (the delegate record is expected as the
+    // first remaining account)
     pub authority: Signer<'info>,
     // Payer for the account resizing.
+    #[account(mut)]
     pub payer: Signer<'info>,
     pub system_program: Program<'info, System>,
 }
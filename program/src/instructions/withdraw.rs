@@ -1,8 +1,16 @@
 use anchor_lang::prelude::*;
 
-use crate::state::CandyGuard;
+use crate::{events::WithdrawEvent, state::CandyGuard};
+
+pub fn withdraw<'info>(ctx: Context<Withdraw<'info>>) -> Result<()> {
+    // the account is closed (and the rent lamports transferred) after the
+    // instruction, so the event reports the current balance
+    emit!(WithdrawEvent {
+        candy_guard: ctx.accounts.candy_guard.key(),
+        authority: ctx.accounts.authority.key(),
+        lamports: ctx.accounts.candy_guard.to_account_info().lamports(),
+    });
 
-pub fn withdraw<'info>(_ctx: Context<Withdraw<'info>>) -> Result<()> {
     Ok(())
 }
 
@@ -4,10 +4,20 @@ use mpl_candy_machine_core::{
     CandyMachine,
 };
 
-use crate::state::{CandyGuard, SEED};
+use crate::{
+    events::WrapEvent,
+    state::{CandyGuard, DelegateRecord, SEED},
+};
 
 pub fn wrap(ctx: Context<Wrap>) -> Result<()> {
     let candy_guard = &ctx.accounts.candy_guard;
+
+    if let Some(role) =
+        DelegateRecord::authorize(candy_guard, &ctx.accounts.authority, ctx.remaining_accounts)?
+    {
+        role.assert_wrap()?;
+    }
+
     // PDA signer for the transaction
     let seeds = [SEED, &candy_guard.base.to_bytes(), &[candy_guard.bump]];
     let signer = [&seeds[..]];
@@ -22,14 +32,19 @@ pub fn wrap(ctx: Context<Wrap>) -> Result<()> {
     // candy machine set_mint_authority CPI
     set_mint_authority(cpi_ctx)?;
 
+    emit!(WrapEvent {
+        candy_guard: candy_guard.key(),
+        candy_machine: ctx.accounts.candy_machine.key(),
+    });
+
     Ok(())
 }
 
 #[derive(Accounts)]
 pub struct Wrap<'info> {
-    #[account(has_one = authority)]
     pub candy_guard: Account<'info, CandyGuard>,
-    // candy machine authority
+    // candy guard authority or delegate (the delegate record is expected as the
+    // first remaining account)
     pub authority: Signer<'info>,
     #[account(
         mut,
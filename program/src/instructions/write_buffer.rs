@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    state::{CandyGuard, ConfigBuffer, BUFFER_DATA_OFFSET, BUFFER_SEED, SEED},
+    utils::resize_account,
+};
+
+pub fn write_buffer(ctx: Context<WriteBuffer>, offset: u32, data: Vec<u8>) -> Result<()> {
+    let account_info = ctx.accounts.buffer.to_account_info();
+    let start = BUFFER_DATA_OFFSET + offset as usize;
+    let end = start + data.len();
+
+    // grows the buffer to fit the data
+    if end > account_info.data_len() {
+        resize_account(
+            &account_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            end,
+        )?;
+    }
+
+    let mut account_data = account_info.data.borrow_mut();
+    account_data[start..end].copy_from_slice(&data);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WriteBuffer<'info> {
+    // the buffer can only be written while its authority is the candy guard authority
+    #[account(
+        constraint = candy_guard.authority == buffer.authority,
+        seeds = [SEED, candy_guard.base.key().as_ref()],
+        bump = candy_guard.bump
+    )]
+    pub candy_guard: Account<'info, CandyGuard>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [BUFFER_SEED, candy_guard.key().as_ref()],
+        bump = buffer.bump
+    )]
+    pub buffer: Account<'info, ConfigBuffer>,
+    pub authority: Signer<'info>,
+    // Payer for the account resizing.
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    pub system_program: Program<'info, System>,
+}
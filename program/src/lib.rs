@@ -4,6 +4,7 @@ use instructions::*;
 use state::*;
 
 pub mod errors;
+pub mod events;
 pub mod guards;
 pub mod instructions;
 pub mod state;
@@ -15,11 +16,70 @@ declare_id!("Guard1JwRhJkVH6XZhzoYxeBVQe872VH6QggF4BWmS9g");
 pub mod candy_guard {
     use super::*;
 
+    /// Accept the authority of the candy guard. The signer must be the pending
+    /// authority staged by `set_authority`.
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        instructions::accept_authority(ctx)
+    }
+
+    /// Add a group to the candy guard configuration.
+    pub fn add_group(ctx: Context<Modify>, label: String, guards: GuardSet) -> Result<()> {
+        instructions::add_group(ctx, label, guards)
+    }
+
+    /// Apply the configuration staged in the buffer to the candy guard, closing the
+    /// buffer. When the configuration requires extending the account by more than
+    /// the maximum increase allowed in one instruction, the account is only extended
+    /// and the instruction must be repeated.
+    pub fn apply_buffer(ctx: Context<ApplyBuffer>) -> Result<()> {
+        instructions::apply_buffer(ctx)
+    }
+
+    /// Clear (disable) a guard of the default guard set or of a group.
+    pub fn clear_guard(
+        ctx: Context<Modify>,
+        label: Option<String>,
+        guard: GuardType,
+    ) -> Result<()> {
+        instructions::clear_guard(ctx, label, guard)
+    }
+
+    /// Close the configuration buffer without applying it.
+    pub fn close_buffer(ctx: Context<CloseBuffer>) -> Result<()> {
+        instructions::close_buffer(ctx)
+    }
+
+    /// Grant a role over the candy guard to a delegate.
+    pub fn create_delegate(ctx: Context<CreateDelegate>, role: DelegateRole) -> Result<()> {
+        instructions::create_delegate(ctx, role)
+    }
+
+    /// Evaluate the guards of a mint transaction without minting. The result of the
+    /// evaluation of each guard is set as the return data of the transaction.
+    pub fn evaluate<'info>(
+        ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+    ) -> Result<()> {
+        instructions::evaluate(ctx, mint_args, label)
+    }
+
     /// Create a new candy guard account.
     pub fn initialize(ctx: Context<Initialize>, data: CandyGuardData) -> Result<()> {
         instructions::initialize(ctx, data)
     }
 
+    /// Create a buffer to stage a configuration of the candy guard over several
+    /// transactions.
+    pub fn initialize_buffer(ctx: Context<InitializeBuffer>) -> Result<()> {
+        instructions::initialize_buffer(ctx)
+    }
+
+    /// Migrate the candy guard data to the current layout version.
+    pub fn migrate(ctx: Context<Migrate>) -> Result<()> {
+        instructions::migrate(ctx)
+    }
+
     /// Mint an NFT from a candy machine wrapped in the candy guard.
     pub fn mint<'info>(
         ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
@@ -29,6 +89,38 @@ pub mod candy_guard {
         instructions::mint(ctx, mint_args, label)
     }
 
+    /// Mint multiple NFTs from a candy machine wrapped in the candy guard in the
+    /// same transaction.
+    pub fn mint_multiple<'info>(
+        ctx: Context<'_, '_, '_, 'info, Mint<'info>>,
+        mint_args: Vec<u8>,
+        label: Option<String>,
+        quantity: u8,
+    ) -> Result<()> {
+        instructions::mint_multiple(ctx, mint_args, label, quantity)
+    }
+
+    /// Quote the price of a mint. The price is set as the return data of the
+    /// transaction.
+    pub fn quote(ctx: Context<Quote>, label: Option<String>) -> Result<()> {
+        instructions::quote(ctx, label)
+    }
+
+    /// Remove a group from the candy guard configuration.
+    pub fn remove_group(ctx: Context<Modify>, label: String) -> Result<()> {
+        instructions::remove_group(ctx, label)
+    }
+
+    /// Rename a group of the candy guard configuration.
+    pub fn rename_group(ctx: Context<Modify>, label: String, new_label: String) -> Result<()> {
+        instructions::rename_group(ctx, label, new_label)
+    }
+
+    /// Revoke the role granted to a delegate, closing the delegate record.
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+        instructions::revoke_delegate(ctx)
+    }
+
     /// Route the transaction to a guard instruction.
     pub fn route<'info>(
         ctx: Context<'_, '_, '_, 'info, Route<'info>>,
@@ -38,6 +130,18 @@ pub mod candy_guard {
         instructions::route(ctx, args, label)
     }
 
+    /// Stage a new authority for the candy guard. The new authority only takes
+    /// effect once it is accepted using `accept_authority`.
+    pub fn set_authority(ctx: Context<SetAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+        instructions::set_authority(ctx, new_authority)
+    }
+
+    /// Set the guards of the default guard set or of a group. Only the guards enabled
+    /// in the specified guard set are modified.
+    pub fn set_guards(ctx: Context<Modify>, label: Option<String>, guards: GuardSet) -> Result<()> {
+        instructions::set_guards(ctx, label, guards)
+    }
+
     /// Remove a candy guard from a candy machine, setting the authority to the
     /// candy guard authority.
     pub fn unwrap(ctx: Context<Unwrap>) -> Result<()> {
@@ -59,4 +163,9 @@ pub mod candy_guard {
     pub fn wrap(ctx: Context<Wrap>) -> Result<()> {
         instructions::wrap(ctx)
     }
+
+    /// Write a chunk of the serialized configuration to the buffer.
+    pub fn write_buffer(ctx: Context<WriteBuffer>, offset: u32, data: Vec<u8>) -> Result<()> {
+        instructions::write_buffer(ctx, offset, data)
+    }
 }
@@ -1,5 +1,6 @@
+use std::collections::BTreeSet;
+
 use anchor_lang::{prelude::*, AnchorDeserialize};
-use solana_program::program_memory::sol_memcmp;
 
 use crate::{errors::CandyGuardError, guards::*};
 use mpl_candy_guard_derive::GuardSet;
@@ -11,8 +12,27 @@ use mpl_candy_guard_derive::GuardSet;
 //  + 32 (authority)
 pub const DATA_OFFSET: usize = 8 + 32 + 1 + 32;
 
+// Current version of the layout of the guards data.
+pub const LAYOUT_VERSION: u8 = 3;
+
+// Flag of the layout header indicating that the guards data is versioned. Accounts
+// created before the layout was versioned start with the (single u64) feature flags
+// of the default guard set, which never have this bit set.
+pub const LAYOUT_HEADER_FLAG: u64 = 0b1u64 << 63;
+
+// Bytes used by the header flag and layout version.
+pub const VERSION_SIZE: usize = 8;
+
+// Bytes used by the layout header:
+//     8 (u64 header flag + layout version)
+//  + 32 (pending authority)
+pub const HEADER_SIZE: usize = VERSION_SIZE + 32;
+
 // Maximim group label size.
-pub const MAX_LABEL_SIZE: usize = 6;
+pub const MAX_LABEL_SIZE: usize = 32;
+
+// Group label size of layout versions before labels were length-prefixed (version 3).
+pub const LEGACY_LABEL_SIZE: usize = 6;
 
 // Seed value for PDA.
 pub const SEED: &[u8] = b"candy_guard";
@@ -48,6 +68,16 @@ pub struct CandyGuard {
     // 14) nft gate
     // 15) nft burn
     // 16) token burn
+    // 17) freeze sol payment
+    // 18) freeze token payment
+    // 19) program gate
+    // 20) allocation
+    // 21) sol payment split
+    // 22) dutch auction
+    // 23) bonding curve
+    // 24) signed voucher
+    // 25) combinator
+    // 26) program guard
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
@@ -65,7 +95,7 @@ pub struct Group {
 }
 
 /// The set of guards available.
-#[derive(GuardSet, AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(GuardSet, AnchorSerialize, AnchorDeserialize, Clone, Debug, Default)]
 pub struct GuardSet {
     /// Last instruction check and bot tax (penalty for invalid transactions).
     pub bot_tax: Option<BotTax>,
@@ -99,10 +129,30 @@ pub struct GuardSet {
     pub nft_burn: Option<NftBurn>,
     /// Token burn guard (burn a specified amount of spl-token).
     pub token_burn: Option<TokenBurn>,
+    /// Freeze sol payment guard (set the price for the mint in lamports with a freeze period).
+    pub freeze_sol_payment: Option<FreezeSolPayment>,
+    /// Freeze token payment guard (set the price for the mint in spl-token amount with a freeze period).
+    pub freeze_token_payment: Option<FreezeTokenPayment>,
+    /// Program gate guard (restricts the programs that can be in a mint transaction).
+    pub program_gate: Option<ProgramGate>,
+    /// Allocation guard (specify the maximum number of mints in a group).
+    pub allocation: Option<Allocation>,
+    /// Sol payment split guard (split the payment in SOL between multiple destinations).
+    pub sol_payment_split: Option<SolPaymentSplit>,
+    /// Dutch auction guard (set the price of the mint in SOL, decreasing over time).
+    pub dutch_auction: Option<DutchAuction>,
+    /// Bonding curve guard (set the price of the mint based on the number of items redeemed).
+    pub bonding_curve: Option<BondingCurve>,
+    /// Signed voucher guard (requires a voucher signed by an off-chain authority).
+    pub signed_voucher: Option<SignedVoucher>,
+    /// Combinator guard (combine other guards using any-of, all-of and not logic).
+    pub combinator: Option<Combinator>,
+    /// Program guard (delegates the validation and actions of the mint to an external program).
+    pub program_guard: Option<ProgramGuard>,
 }
 
 /// Available guard types.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum GuardType {
     BotTax,
     SolPayment,
@@ -120,13 +170,35 @@ pub enum GuardType {
     NftGate,
     NftBurn,
     TokenBurn,
+    FreezeSolPayment,
+    FreezeTokenPayment,
+    ProgramGate,
+    Allocation,
+    SolPaymentSplit,
+    DutchAuction,
+    BondingCurve,
+    SignedVoucher,
+    Combinator,
+    ProgramGuard,
 }
 
 impl CandyGuardData {
-    /// Serialize the candy guard data into the specified data array.
+    /// Serialize the candy guard data into the specified data array using the current
+    /// layout version.
     pub fn save(&self, data: &mut [u8]) -> Result<()> {
+        // the pending authority is only preserved when the header of the layout used by
+        // the account includes it (version 2 onwards)
+        if !matches!(Self::layout_version(data)?, 2..=LAYOUT_VERSION) {
+            data[VERSION_SIZE..HEADER_SIZE].copy_from_slice(Pubkey::default().as_ref());
+        }
+        // layout header
+        let header = LAYOUT_HEADER_FLAG | LAYOUT_VERSION as u64;
+        data[0..VERSION_SIZE].copy_from_slice(&u64::to_le_bytes(header));
+        let data = &mut data[HEADER_SIZE..];
         let mut cursor = 0;
 
+        Self::assert_guard_set(&self.default)?;
+
         // saves the 'default' guard set
         let _ = self.default.to_data(data)?;
         cursor += self.default.size();
@@ -142,13 +214,25 @@ impl CandyGuardData {
 
         // saves each individual 'groups' guard set
         if let Some(groups) = &self.groups {
+            let mut labels = BTreeSet::new();
+
             for group in groups {
-                // label
+                // the guards of a group are evaluated together with the default guards
+                let mut guards = self.default.clone();
+                guards.merge(group.guards.clone());
+                Self::assert_guard_set(&guards)?;
+                // label (u8 length prefix + label bytes)
                 if group.label.len() > MAX_LABEL_SIZE {
                     return err!(CandyGuardError::LabelExceededLength);
                 }
+                if !labels.insert(group.label.as_str()) {
+                    msg!("Duplicate group label: {}", group.label);
+                    return err!(CandyGuardError::DuplicateGroupLabel);
+                }
+                data[cursor] = group.label.len() as u8;
+                cursor += 1;
                 data[cursor..cursor + group.label.len()].copy_from_slice(group.label.as_bytes());
-                cursor += MAX_LABEL_SIZE;
+                cursor += group.label.len();
                 // guard set
                 let _ = group.guards.to_data(&mut data[cursor..])?;
                 cursor += group.guards.size();
@@ -158,11 +242,63 @@ impl CandyGuardData {
         Ok(())
     }
 
+    /// Validates that the guards enabled on a guard set can be used together.
+    fn assert_guard_set(guard_set: &GuardSet) -> Result<()> {
+        // both guards freeze the minted NFT
+        if guard_set.freeze_sol_payment.is_some() && guard_set.freeze_token_payment.is_some() {
+            return err!(CandyGuardError::IncompatibleFreezeGuards);
+        }
+
+        // guards combined must be enabled, otherwise they would not be evaluated
+        if let Some(combinator) = &guard_set.combinator {
+            combinator.assert_members(guard_set)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the guard set with the specified label (the default guard set when the
+    /// label is `None`).
+    pub fn guard_set_mut(&mut self, label: Option<&str>) -> Result<&mut GuardSet> {
+        if let Some(label) = label {
+            Ok(&mut self.group_mut(label)?.guards)
+        } else {
+            Ok(&mut self.default)
+        }
+    }
+
+    /// Returns the group with the specified label.
+    pub fn group_mut(&mut self, label: &str) -> Result<&mut Group> {
+        self.groups
+            .iter_mut()
+            .flatten()
+            .find(|group| group.label == label)
+            .ok_or_else(|| error!(CandyGuardError::GroupNotFound))
+    }
+
+    /// Returns whether a group with the specified label exists.
+    pub fn has_group(&self, label: &str) -> bool {
+        self.groups
+            .iter()
+            .flatten()
+            .any(|group| group.label == label)
+    }
+
+    /// Returns the serialized candy guard data using the current layout version.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut data = vec![0; self.size() - DATA_OFFSET];
+        self.save(&mut data)?;
+
+        Ok(data)
+    }
+
     /// Deserializes the guards. Only attempts the deserialization of individuals guards
     /// if the data slice is large enough.
     pub fn load(data: &[u8]) -> Result<Box<Self>> {
-        let (default, _) = GuardSet::from_data(data)?;
-        let mut cursor = default.size();
+        let version = Self::layout_version(data)?;
+        let data = Self::guards_data(data)?;
+        let (default, features) = GuardSet::from_data(data, version)?;
+        let mut cursor = GuardSet::bytes_count(&features, version);
 
         let group_counter = u32::from_le_bytes(*arrayref::array_ref![data, cursor, 4]);
         cursor += 4;
@@ -171,12 +307,12 @@ impl CandyGuardData {
             let mut groups = Vec::with_capacity(group_counter as usize);
 
             for _i in 0..group_counter {
-                let slice: &[u8] = &data[cursor..cursor + MAX_LABEL_SIZE];
+                let (slice, label_size) = Self::read_label(&data[cursor..], version)?;
                 let label = String::from_utf8(slice.to_vec())
                     .map_err(|_| CandyGuardError::DeserializationError)?;
-                cursor += MAX_LABEL_SIZE;
-                let (guards, _) = GuardSet::from_data(&data[cursor..])?;
-                cursor += guards.size();
+                cursor += label_size;
+                let (guards, features) = GuardSet::from_data(&data[cursor..], version)?;
+                cursor += GuardSet::bytes_count(&features, version);
                 groups.push(Group { label, guards });
             }
 
@@ -188,10 +324,20 @@ impl CandyGuardData {
         Ok(Box::new(Self { default, groups }))
     }
 
+    /// Returns the default guard set, ignoring the groups.
+    pub fn default_set(data: &[u8]) -> Result<Box<GuardSet>> {
+        let version = Self::layout_version(data)?;
+        let (default, _) = GuardSet::from_data(Self::guards_data(data)?, version)?;
+
+        Ok(Box::new(default))
+    }
+
     pub fn active_set(data: &[u8], label: Option<String>) -> Result<Box<GuardSet>> {
+        let version = Self::layout_version(data)?;
+        let data = Self::guards_data(data)?;
         // default guard set
-        let (mut default, _) = GuardSet::from_data(data)?;
-        let mut cursor = default.size();
+        let (mut default, features) = GuardSet::from_data(data, version)?;
+        let mut cursor = GuardSet::bytes_count(&features, version);
 
         // number of groups
         let group_counter = u32::from_le_bytes(*arrayref::array_ref![data, cursor, 4]);
@@ -199,19 +345,19 @@ impl CandyGuardData {
 
         if group_counter > 0 {
             if let Some(label) = label {
-                let label_slice = label.as_bytes();
                 // retrieves the selected gorup
                 for _i in 0..group_counter {
-                    if sol_memcmp(label_slice, &data[cursor..], label_slice.len()) == 0 {
-                        cursor += MAX_LABEL_SIZE;
-                        let (guards, _) = GuardSet::from_data(&data[cursor..])?;
+                    let (label_slice, label_size) = Self::read_label(&data[cursor..], version)?;
+                    cursor += label_size;
+
+                    if label_slice == label.as_bytes() {
+                        let (guards, _) = GuardSet::from_data(&data[cursor..], version)?;
                         default.merge(guards);
                         // we found our group
                         return Ok(Box::new(default));
                     } else {
-                        cursor += MAX_LABEL_SIZE;
-                        let features = u64::from_le_bytes(*arrayref::array_ref![data, cursor, 8]);
-                        cursor += GuardSet::bytes_count(features);
+                        let features = Features::from_data(&data[cursor..])?;
+                        cursor += GuardSet::bytes_count(&features, version);
                     }
                 }
                 return err!(CandyGuardError::GroupNotFound);
@@ -225,17 +371,226 @@ impl CandyGuardData {
         Ok(Box::new(default))
     }
 
+    /// Returns the layout version of the guards data. Accounts created before the
+    /// layout was versioned do not have a header and are reported as version `0`.
+    pub fn layout_version(data: &[u8]) -> Result<u8> {
+        let header = data
+            .get(0..VERSION_SIZE)
+            .ok_or(CandyGuardError::DeserializationError)?;
+        let header = u64::from_le_bytes(*arrayref::array_ref![header, 0, VERSION_SIZE]);
+
+        if header & LAYOUT_HEADER_FLAG > 0 {
+            Ok((header & !LAYOUT_HEADER_FLAG) as u8)
+        } else {
+            Ok(0)
+        }
+    }
+
+    /// Returns the guards data following the layout header. The guards data of previous
+    /// versions differs from the current version on the format of the group labels
+    /// (versions before 3) and on the size of guards whose configuration changed (see
+    /// `Guard::size_for`), so it must be read using its layout version until the account
+    /// is migrated.
+    pub fn guards_data(data: &[u8]) -> Result<&[u8]> {
+        match Self::layout_version(data)? {
+            0 => Ok(data),
+            // the header of version 1 does not include the pending authority
+            1 => Ok(&data[VERSION_SIZE..]),
+            2..=LAYOUT_VERSION => Ok(&data[HEADER_SIZE..]),
+            version => {
+                msg!("Unsupported layout version {}", version);
+                err!(CandyGuardError::UnsupportedLayoutVersion)
+            }
+        }
+    }
+
+    /// Returns the label at the start of the data slice and the number of bytes it uses.
+    /// Layout versions before 3 store labels using a fixed number of bytes padded with
+    /// zeros, while the current version uses a length prefix.
+    fn read_label(data: &[u8], version: u8) -> Result<(&[u8], usize)> {
+        if version < 3 {
+            let slice = data
+                .get(0..LEGACY_LABEL_SIZE)
+                .ok_or(CandyGuardError::DeserializationError)?;
+            let length = slice.iter().rposition(|b| *b != 0).map_or(0, |i| i + 1);
+
+            Ok((&slice[..length], LEGACY_LABEL_SIZE))
+        } else {
+            let length = *data.first().ok_or(CandyGuardError::DeserializationError)? as usize;
+            let slice = data
+                .get(1..1 + length)
+                .ok_or(CandyGuardError::DeserializationError)?;
+
+            Ok((slice, 1 + length))
+        }
+    }
+
+    /// Returns the authority staged to replace the current authority, if any. Accounts
+    /// using a layout version before 2 do not have a pending authority.
+    pub fn pending_authority(data: &[u8]) -> Result<Option<Pubkey>> {
+        if !matches!(Self::layout_version(data)?, 2..=LAYOUT_VERSION) {
+            return Ok(None);
+        }
+
+        let pending_authority =
+            Pubkey::new_from_array(*arrayref::array_ref![data, VERSION_SIZE, 32]);
+
+        Ok(if pending_authority == Pubkey::default() {
+            None
+        } else {
+            Some(pending_authority)
+        })
+    }
+
+    /// Stores the authority staged to replace the current authority. The account must
+    /// use a layout version with a pending authority (version 2 onwards).
+    pub fn set_pending_authority(data: &mut [u8], authority: Option<Pubkey>) -> Result<()> {
+        if !matches!(Self::layout_version(data)?, 2..=LAYOUT_VERSION) {
+            return err!(CandyGuardError::MigrationRequired);
+        }
+
+        let authority = authority.unwrap_or_default();
+        data[VERSION_SIZE..HEADER_SIZE].copy_from_slice(authority.as_ref());
+
+        Ok(())
+    }
+
+    /// Returns the groups in the order they are stored, each with its guard set
+    /// merged with the default guard set.
+    pub fn merged_groups(data: &[u8]) -> Result<Vec<Group>> {
+        let candy_guard_data = Self::load(data)?;
+        let mut merged = vec![];
+
+        if let Some(groups) = candy_guard_data.groups {
+            for group in groups {
+                let mut guards = candy_guard_data.default.clone();
+                guards.merge(group.guards);
+                merged.push(Group {
+                    label: group.label,
+                    guards,
+                });
+            }
+        }
+
+        Ok(merged)
+    }
+
     pub fn size(&self) -> usize {
-        let mut size = DATA_OFFSET + self.default.size();
+        let mut size = DATA_OFFSET + HEADER_SIZE + self.default.size();
         size += 4; // u32 (number of groups)
 
         if let Some(groups) = &self.groups {
             size += groups
                 .iter()
-                .map(|group| MAX_LABEL_SIZE + group.guards.size())
+                .map(|group| 1 + group.label.len() + group.guards.size())
                 .sum::<usize>();
         }
 
         size
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Guard set with an allow list followed by a mint limit, using the layout of
+    /// accounts created before the layout was versioned.
+    fn legacy_guard_set(merkle_root: [u8; 32], id: u8, limit: u16) -> Vec<u8> {
+        let mut data = vec![];
+        data.extend_from_slice(&u64::to_le_bytes(AllowList::mask() | MintLimit::mask()));
+        data.extend_from_slice(&merkle_root);
+        data.push(id);
+        data.extend_from_slice(&u16::to_le_bytes(limit));
+        data
+    }
+
+    fn assert_guard_set(guards: &GuardSet, merkle_root: [u8; 32], id: u8, limit: u16) {
+        let allow_list = guards.allow_list.as_ref().unwrap();
+        assert_eq!(allow_list.merkle_root, merkle_root);
+        assert_eq!(allow_list.proof_ttl, None);
+
+        let mint_limit = guards.mint_limit.as_ref().unwrap();
+        assert_eq!(mint_limit.id, id);
+        assert_eq!(mint_limit.limit, limit);
+    }
+
+    #[test]
+    fn migrate_legacy_allow_list() {
+        let mut data = legacy_guard_set([1; 32], 1, 5);
+        // one group with a zero-padded label
+        data.extend_from_slice(&u32::to_le_bytes(1));
+        data.extend_from_slice(b"vip\0\0\0");
+        data.extend_from_slice(&legacy_guard_set([2; 32], 2, 10));
+
+        assert_eq!(CandyGuardData::layout_version(&data).unwrap(), 0);
+
+        let candy_guard_data = CandyGuardData::load(&data).unwrap();
+        assert_guard_set(&candy_guard_data.default, [1; 32], 1, 5);
+        let groups = candy_guard_data.groups.as_ref().unwrap();
+        assert_eq!(groups[0].label, "vip");
+        assert_guard_set(&groups[0].guards, [2; 32], 2, 10);
+
+        let active = CandyGuardData::active_set(&data, Some("vip".to_string())).unwrap();
+        assert_guard_set(&active, [2; 32], 2, 10);
+
+        // rewrites the guards data using the current layout
+        let migrated = candy_guard_data.to_bytes().unwrap();
+        assert_eq!(
+            CandyGuardData::layout_version(&migrated).unwrap(),
+            LAYOUT_VERSION
+        );
+
+        let candy_guard_data = CandyGuardData::load(&migrated).unwrap();
+        assert_guard_set(&candy_guard_data.default, [1; 32], 1, 5);
+        let groups = candy_guard_data.groups.as_ref().unwrap();
+        assert_eq!(groups[0].label, "vip");
+        assert_guard_set(&groups[0].guards, [2; 32], 2, 10);
+    }
+
+    #[test]
+    fn reject_incompatible_freeze_guards() {
+        let freeze_sol_payment = FreezeSolPayment {
+            lamports: 1,
+            destination: Pubkey::default(),
+        };
+        let freeze_token_payment = FreezeTokenPayment {
+            amount: 1,
+            mint: Pubkey::default(),
+            destination_ata: Pubkey::default(),
+        };
+
+        // both guards on the same guard set
+        let mut default = GuardSet {
+            freeze_sol_payment: Some(freeze_sol_payment),
+            ..Default::default()
+        };
+        default.freeze_token_payment = Some(freeze_token_payment.clone());
+
+        let candy_guard_data = CandyGuardData {
+            default,
+            groups: None,
+        };
+        assert_eq!(
+            candy_guard_data.to_bytes().unwrap_err(),
+            error!(CandyGuardError::IncompatibleFreezeGuards)
+        );
+
+        // guards on the default guard set and on a group
+        let mut candy_guard_data = candy_guard_data;
+        candy_guard_data.default.freeze_token_payment = None;
+        assert!(candy_guard_data.to_bytes().is_ok());
+
+        candy_guard_data.groups = Some(vec![Group {
+            label: "vip".to_string(),
+            guards: GuardSet {
+                freeze_token_payment: Some(freeze_token_payment),
+                ..Default::default()
+            },
+        }]);
+        assert_eq!(
+            candy_guard_data.to_bytes().unwrap_err(),
+            error!(CandyGuardError::IncompatibleFreezeGuards)
+        );
+    }
+}
@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+
+// Seed value for the configuration buffer PDA.
+pub const BUFFER_SEED: &[u8] = b"config_buffer";
+
+// Bytes offset for the start of the buffer data:
+//     8 (discriminator)
+//  + 32 (candy guard)
+//  + 32 (authority)
+//  +  1 (bump)
+pub const BUFFER_DATA_OFFSET: usize = 8 + 32 + 32 + 1;
+
+/// Buffer used to stage a configuration of the candy guard over several transactions.
+/// The PDA is derived using the seed `["config_buffer", candy guard pubkey]`.
+#[account]
+#[derive(Default)]
+pub struct ConfigBuffer {
+    // Candy guard of the buffer
+    pub candy_guard: Pubkey,
+    // Authority allowed to write to the buffer
+    pub authority: Pubkey,
+    // Bump seed
+    pub bump: u8,
+    // after this there is a flexible amount of data with the serialized
+    // configuration (CandyGuardData struct); the account is resized as
+    // the data is written
+}
@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+
+use super::{CandyGuard, CandyGuardData, GuardType, MAX_LABEL_SIZE};
+use crate::errors::CandyGuardError;
+
+// Seed value for the delegate record PDA.
+pub const DELEGATE_SEED: &[u8] = b"delegate";
+
+/// Record granting a role over a candy guard to a delegate. The PDA is derived
+/// using the seed `["delegate", candy guard pubkey, delegate pubkey, role seed]`.
+#[account]
+pub struct DelegateRecord {
+    // Candy guard of the delegate record
+    pub candy_guard: Pubkey,
+    // Delegate public key
+    pub delegate: Pubkey,
+    // Authority that granted the role
+    pub authority: Pubkey,
+    // Role granted to the delegate
+    pub role: DelegateRole,
+    // Bump seed
+    pub bump: u8,
+}
+
+impl DelegateRecord {
+    /// Returns the size of a delegate record with the specified role.
+    pub fn size(role: &DelegateRole) -> usize {
+        8 // discriminator
+        + 32 // candy guard
+        + 32 // delegate
+        + 32 // authority
+        + role.size()
+        + 1 // bump
+    }
+
+    /// Asserts that the signer is the authority of the candy guard or a delegate. When
+    /// the signer is not the authority, the delegate record must be the first remaining
+    /// account and the role granted to the delegate is returned. Only records granted
+    /// by the current authority are valid, so delegates do not survive an authority
+    /// transfer.
+    pub fn authorize(
+        candy_guard: &Account<CandyGuard>,
+        signer: &Signer,
+        remaining_accounts: &[AccountInfo],
+    ) -> Result<Option<DelegateRole>> {
+        if signer.key() == candy_guard.authority {
+            return Ok(None);
+        }
+
+        let record_info = remaining_accounts
+            .first()
+            .ok_or(CandyGuardError::InvalidDelegate)?;
+        let record = Account::<DelegateRecord>::try_from(record_info)
+            .map_err(|_| error!(CandyGuardError::InvalidDelegate))?;
+
+        if record.candy_guard != candy_guard.key() || record.delegate != signer.key() {
+            return err!(CandyGuardError::InvalidDelegate);
+        }
+
+        if record.authority != candy_guard.authority {
+            msg!("Delegate record was granted by a previous authority");
+            return err!(CandyGuardError::InvalidDelegate);
+        }
+
+        Ok(Some(record.role.clone()))
+    }
+}
+
+/// Roles that can be granted to a delegate.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub enum DelegateRole {
+    /// Update the specified guards of a guard set (the default guard set when the
+    /// label is `None`).
+    Update {
+        label: Option<String>,
+        guards: Vec<GuardType>,
+    },
+    /// Add the candy guard to or remove it from a candy machine.
+    Wrap,
+    /// Close the mint counters of the mint limit guard.
+    CloseCounters,
+}
+
+impl DelegateRole {
+    /// Returns the seed used to derive the delegate record PDA, so a delegate can
+    /// hold one record of each role.
+    pub fn seed(&self) -> u8 {
+        match self {
+            DelegateRole::Update { .. } => 0,
+            DelegateRole::Wrap => 1,
+            DelegateRole::CloseCounters => 2,
+        }
+    }
+
+    /// Returns the serialized size of the role.
+    pub fn size(&self) -> usize {
+        1 + match self {
+            DelegateRole::Update { label, guards } => {
+                1 + label.as_ref().map_or(0, |label| 4 + label.len()) // option + label
+                + 4 + guards.len() // vec + guard types
+            }
+            DelegateRole::Wrap | DelegateRole::CloseCounters => 0,
+        }
+    }
+
+    /// Validates the configuration of the role.
+    pub fn validate(&self) -> Result<()> {
+        if let DelegateRole::Update {
+            label: Some(label), ..
+        } = self
+        {
+            if label.len() > MAX_LABEL_SIZE {
+                return err!(CandyGuardError::LabelExceededLength);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Asserts that the role allows adding the candy guard to or removing it from a
+    /// candy machine.
+    pub fn assert_wrap(&self) -> Result<()> {
+        match self {
+            DelegateRole::Wrap => Ok(()),
+            _ => err!(CandyGuardError::DelegateRoleNotAllowed),
+        }
+    }
+
+    /// Asserts that the role allows closing mint counters.
+    pub fn assert_close_counters(&self) -> Result<()> {
+        match self {
+            DelegateRole::CloseCounters => Ok(()),
+            _ => err!(CandyGuardError::DelegateRoleNotAllowed),
+        }
+    }
+
+    /// Asserts that the updated configuration only differs from the current one on
+    /// the guards of the guard set in the scope of the role.
+    pub fn assert_update(&self, current: &CandyGuardData, data: &CandyGuardData) -> Result<()> {
+        let (label, guards) = match self {
+            DelegateRole::Update { label, guards } => (label, guards),
+            _ => return err!(CandyGuardError::DelegateRoleNotAllowed),
+        };
+
+        // the expected configuration is the current one with the guards in scope
+        // replaced by the ones of the updated configuration
+        let mut expected = current.clone();
+
+        let target = expected.guard_set_mut(label.as_deref())?;
+        let source = if let Some(label) = label {
+            &data
+                .groups
+                .iter()
+                .flatten()
+                .find(|group| &group.label == label)
+                .ok_or(CandyGuardError::GroupNotFound)?
+                .guards
+        } else {
+            &data.default
+        };
+
+        for guard in guards {
+            target.set_guard(guard, source);
+        }
+
+        // compares the serialized data, since guard sets do not implement `PartialEq`
+        if expected.to_bytes()? != data.to_bytes()? {
+            msg!("Delegate can only update {:?} of the guard set", guards);
+            return err!(CandyGuardError::DelegateRoleNotAllowed);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn role_seed_and_size() {
+        let roles = [
+            DelegateRole::Update {
+                label: Some("OG".to_string()),
+                guards: vec![GuardType::StartDate, GuardType::EndDate],
+            },
+            DelegateRole::Wrap,
+            DelegateRole::CloseCounters,
+        ];
+
+        for (index, role) in roles.iter().enumerate() {
+            assert_eq!(role.seed() as usize, index);
+            assert_eq!(role.size(), role.try_to_vec().unwrap().len());
+        }
+    }
+
+    #[test]
+    fn close_counters_role() {
+        assert!(DelegateRole::CloseCounters.assert_close_counters().is_ok());
+        assert!(DelegateRole::CloseCounters.assert_wrap().is_err());
+        assert!(DelegateRole::Wrap.assert_close_counters().is_err());
+    }
+}
@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::CandyGuardError;
+
+// Maximum number of words of the feature bitmap.
+pub const MAX_FEATURE_WORDS: usize = 4;
+
+// Number of guard flags in each word of the feature bitmap.
+pub const FEATURES_PER_WORD: usize = 63;
+
+// Flag indicating that another word of the feature bitmap follows.
+pub const FEATURE_CONTINUATION: u64 = 0b1u64 << 63;
+
+/// Feature bitmap indicating which guards are serialized in a guard set.
+///
+/// The bitmap is serialized as a sequence of `u64` words, where the first 63 bits of
+/// each word are guard flags and the last bit indicates whether another word follows.
+/// Since the continuation bit of a single `u64` feature flag is never set, guard sets
+/// written before the bitmap was extended are still valid.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Features {
+    words: [u64; MAX_FEATURE_WORDS],
+}
+
+impl Features {
+    /// Deserializes the feature bitmap from the start of the data slice.
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        let mut features = Features::default();
+
+        for (index, word) in features.words.iter_mut().enumerate() {
+            let offset = index * 8;
+            let bytes = data
+                .get(offset..offset + 8)
+                .ok_or(CandyGuardError::DeserializationError)?;
+            let value = u64::from_le_bytes(
+                bytes
+                    .try_into()
+                    .map_err(|_| CandyGuardError::DeserializationError)?,
+            );
+            *word = value & !FEATURE_CONTINUATION;
+
+            if value & FEATURE_CONTINUATION == 0 {
+                return Ok(features);
+            }
+        }
+
+        err!(CandyGuardError::DeserializationError)
+    }
+
+    /// Serializes the feature bitmap into the start of the data slice.
+    pub fn to_data(&self, data: &mut [u8]) -> Result<()> {
+        let count = self.word_count();
+
+        if data.len() < count * 8 {
+            return err!(CandyGuardError::InvalidAccountSize);
+        }
+
+        for index in 0..count {
+            let mut value = self.words[index];
+
+            if index + 1 < count {
+                value |= FEATURE_CONTINUATION;
+            }
+
+            data[index * 8..(index + 1) * 8].copy_from_slice(&u64::to_le_bytes(value));
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of bytes used by the serialized feature bitmap.
+    pub fn size(&self) -> usize {
+        self.word_count() * 8
+    }
+
+    /// Returns the number of words in use, which is at least one.
+    fn word_count(&self) -> usize {
+        self.words
+            .iter()
+            .rposition(|word| *word > 0)
+            .map_or(1, |index| index + 1)
+    }
+
+    /// Returns the words in use of the feature bitmap, without the continuation flag.
+    pub fn words(&self) -> Vec<u64> {
+        self.words[..self.word_count()].to_vec()
+    }
+
+    /// Sets the flags of the other feature bitmap.
+    pub fn insert(&mut self, other: &Features) {
+        for (word, other) in self.words.iter_mut().zip(other.words.iter()) {
+            *word |= other;
+        }
+    }
+
+    /// Returns whether any of the flags of the mask are set on the specified word.
+    pub fn contains(&self, word: usize, mask: u64) -> bool {
+        self.words[word] & mask > 0
+    }
+
+    /// Sets the flags of the mask on the specified word.
+    pub fn set(&mut self, word: usize, mask: u64) {
+        self.words[word] |= mask & !FEATURE_CONTINUATION;
+    }
+
+    /// Clears the flags of the mask on the specified word.
+    pub fn clear(&mut self, word: usize, mask: u64) {
+        self.words[word] &= !mask;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_word_format() {
+        // guard sets written before the bitmap was extended use a single `u64`
+        let mut data = u64::to_le_bytes(0b1011).to_vec();
+        data.extend_from_slice(&[0xff; 16]);
+
+        let features = Features::from_data(&data).unwrap();
+        assert!(features.contains(0, 0b1011));
+        assert!(!features.contains(0, !0b1011));
+        assert_eq!(features.size(), 8);
+
+        let mut serialized = [0u8; 8];
+        features.to_data(&mut serialized).unwrap();
+        assert_eq!(serialized[..], data[..8]);
+    }
+
+    #[test]
+    fn multiple_words() {
+        let mut features = Features::default();
+        assert_eq!(features.size(), 8);
+
+        features.set(0, 0b1);
+        features.set(2, 0b100 | FEATURE_CONTINUATION);
+        assert!(features.contains(0, 0b1));
+        assert!(!features.contains(1, 0b1));
+        assert!(features.contains(2, 0b100));
+        assert!(!features.contains(2, FEATURE_CONTINUATION));
+        assert_eq!(features.size(), 24);
+
+        let mut data = [0u8; 24];
+        features.to_data(&mut data).unwrap();
+        assert_eq!(
+            u64::from_le_bytes(data[0..8].try_into().unwrap()),
+            0b1 | FEATURE_CONTINUATION
+        );
+        assert_eq!(
+            u64::from_le_bytes(data[8..16].try_into().unwrap()),
+            FEATURE_CONTINUATION
+        );
+        assert_eq!(u64::from_le_bytes(data[16..24].try_into().unwrap()), 0b100);
+        assert_eq!(Features::from_data(&data).unwrap(), features);
+
+        // the data must fit all words in use
+        assert!(features.to_data(&mut [0u8; 16]).is_err());
+
+        features.clear(2, 0b100);
+        assert!(!features.contains(2, 0b100));
+        assert_eq!(features.size(), 8);
+
+        features.set(1, 0b10);
+        assert_eq!(features.size(), 16);
+    }
+
+    #[test]
+    fn malformed_data() {
+        // missing the word following a continuation flag
+        let data = u64::to_le_bytes(FEATURE_CONTINUATION);
+        assert!(Features::from_data(&data).is_err());
+
+        // more words than the maximum supported
+        let data = u64::to_le_bytes(FEATURE_CONTINUATION).repeat(MAX_FEATURE_WORDS + 1);
+        assert!(Features::from_data(&data).is_err());
+
+        assert!(Features::from_data(&[0u8; 4]).is_err());
+    }
+}
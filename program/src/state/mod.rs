@@ -1,3 +1,9 @@
 pub use candy_guard::*;
+pub use config_buffer::*;
+pub use delegate::*;
+pub use features::*;
 
 pub mod candy_guard;
+pub mod config_buffer;
+pub mod delegate;
+pub mod features;
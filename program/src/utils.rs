@@ -1,14 +1,36 @@
 use anchor_lang::prelude::*;
+use arrayref::array_ref;
 use solana_program::{
-    program::invoke_signed,
+    entrypoint::MAX_PERMITTED_DATA_INCREASE,
+    instruction::{AccountMeta, Instruction},
+    program::{invoke, invoke_signed},
     program_memory::sol_memcmp,
     program_pack::{IsInitialized, Pack},
     pubkey::PUBKEY_BYTES,
+    system_instruction,
+};
+use spl_token::{
+    instruction::TokenInstruction,
+    state::{Account as TokenAccount, Mint as TokenMint},
 };
-use spl_associated_token_account::get_associated_token_address;
 
 use crate::errors::CandyGuardError;
 
+/// Token-2022 program (spl-token with extensions).
+pub mod spl_token_2022 {
+    solana_program::declare_id!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+}
+
+/// Offset of the account type on Token-2022 accounts with extensions. The base
+/// mint and token account data are padded to the size of a token account.
+const ACCOUNT_TYPE_OFFSET: usize = TokenAccount::LEN;
+
+/// Type of the Token-2022 `TransferFeeConfig` mint extension.
+const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;
+
+/// Maximum value of basis points (100%).
+const MAX_FEE_BASIS_POINTS: u64 = 10_000;
+
 /// TokenBurnParams
 pub struct TokenBurnParams<'a: 'b, 'b> {
     /// mint
@@ -19,6 +41,8 @@ pub struct TokenBurnParams<'a: 'b, 'b> {
     pub source: AccountInfo<'a>,
     /// amount
     pub amount: u64,
+    /// decimals
+    pub decimals: u8,
     /// authority
     /// CHECK: account checked in CPI
     pub authority: AccountInfo<'a>,
@@ -37,8 +61,13 @@ pub struct TokenTransferParams<'a: 'b, 'b> {
     /// destination
     /// CHECK: account checked in CPI
     pub destination: AccountInfo<'a>,
+    /// mint
+    /// CHECK: account checked in CPI
+    pub mint: AccountInfo<'a>,
     /// amount
     pub amount: u64,
+    /// decimals
+    pub decimals: u8,
     /// authority
     /// CHECK: account checked in CPI
     pub authority: AccountInfo<'a>,
@@ -62,16 +91,47 @@ pub fn assert_initialized<T: Pack + IsInitialized>(account_info: &AccountInfo) -
     }
 }
 
+/// Unpacks the base state of an spl-token (or Token-2022) account, ignoring any
+/// extension data.
+pub fn assert_initialized_base<T: Pack + IsInitialized>(account_info: &AccountInfo) -> Result<T> {
+    let data = account_info.data.borrow();
+    let base = data
+        .get(..T::LEN)
+        .ok_or(CandyGuardError::InvalidAccountSize)?;
+    let account: T = T::unpack_unchecked(base)?;
+    if !account.is_initialized() {
+        err!(CandyGuardError::Uninitialized)
+    } else {
+        Ok(account)
+    }
+}
+
+/// Derives the address of an associated token account for the specified token program.
+pub fn get_associated_token_address_with_program_id(
+    wallet: &Pubkey,
+    mint: &Pubkey,
+    token_program: &Pubkey,
+) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &spl_associated_token_account::ID,
+    )
+    .0
+}
+
 pub fn assert_is_ata(
     ata: &AccountInfo,
     wallet: &Pubkey,
     mint: &Pubkey,
-) -> core::result::Result<spl_token::state::Account, ProgramError> {
-    assert_owned_by(ata, &spl_token::id())?;
-    let ata_account: spl_token::state::Account = assert_initialized(ata)?;
+) -> core::result::Result<TokenAccount, ProgramError> {
+    assert_owned_by_token_program(ata)?;
+    let ata_account: TokenAccount = assert_initialized_base(ata)?;
     assert_keys_equal(&ata_account.owner, wallet)?;
     assert_keys_equal(&ata_account.mint, mint)?;
-    assert_keys_equal(&get_associated_token_address(wallet, mint), ata.key)?;
+    assert_keys_equal(
+        &get_associated_token_address_with_program_id(wallet, mint, ata.owner),
+        ata.key,
+    )?;
     Ok(ata_account)
 }
 
@@ -79,14 +139,30 @@ pub fn assert_is_token_account(
     ta: &AccountInfo,
     wallet: &Pubkey,
     mint: &Pubkey,
-) -> core::result::Result<spl_token::state::Account, ProgramError> {
-    assert_owned_by(ta, &spl_token::id())?;
-    let token_account: spl_token::state::Account = assert_initialized(ta)?;
+) -> core::result::Result<TokenAccount, ProgramError> {
+    assert_owned_by_token_program(ta)?;
+    let token_account: TokenAccount = assert_initialized_base(ta)?;
     assert_keys_equal(&token_account.owner, wallet)?;
     assert_keys_equal(&token_account.mint, mint)?;
     Ok(token_account)
 }
 
+/// Asserts that the account is a mint of either spl-token or Token-2022 program
+/// and returns its base state.
+pub fn assert_is_mint(mint: &AccountInfo) -> Result<TokenMint> {
+    assert_owned_by_token_program(mint)?;
+    assert_initialized_base(mint)
+}
+
+/// Asserts that the account is a token program account owning the mint.
+pub fn assert_token_program(token_program: &AccountInfo, mint: &AccountInfo) -> Result<()> {
+    assert_keys_equal(token_program.key, mint.owner)?;
+    if !is_token_program(token_program.key) {
+        return err!(CandyGuardError::IncorrectOwner);
+    }
+    Ok(())
+}
+
 pub fn assert_keys_equal(key1: &Pubkey, key2: &Pubkey) -> Result<()> {
     if !cmp_pubkeys(key1, key2) {
         err!(CandyGuardError::PublicKeyMismatch)
@@ -103,6 +179,116 @@ pub fn assert_owned_by(account: &AccountInfo, owner: &Pubkey) -> Result<()> {
     }
 }
 
+pub fn assert_owned_by_token_program(account: &AccountInfo) -> Result<()> {
+    if !is_token_program(account.owner) {
+        err!(CandyGuardError::IncorrectOwner)
+    } else {
+        Ok(())
+    }
+}
+
+/// Returns `true` if the address is either the spl-token or Token-2022 program.
+pub fn is_token_program(program_id: &Pubkey) -> bool {
+    cmp_pubkeys(program_id, &spl_token::ID) || cmp_pubkeys(program_id, &spl_token_2022::ID)
+}
+
+/// Returns the amount that must be transferred so that the destination receives
+/// `amount` tokens, taking into account the Token-2022 transfer fee of the mint
+/// (if any).
+pub fn get_pre_fee_amount(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if let Some((maximum_fee, basis_points)) = get_transfer_fee(mint)? {
+        calculate_pre_fee_amount(amount, maximum_fee, basis_points)
+    } else {
+        Ok(amount)
+    }
+}
+
+/// Returns the amount that must be transferred so that the destination receives
+/// `amount` tokens for the specified transfer fee.
+fn calculate_pre_fee_amount(amount: u64, maximum_fee: u64, basis_points: u64) -> Result<u64> {
+    if basis_points == 0 || amount == 0 {
+        Ok(amount)
+    } else if basis_points >= MAX_FEE_BASIS_POINTS {
+        amount
+            .checked_add(maximum_fee)
+            .ok_or_else(|| CandyGuardError::NumericalOverflowError.into())
+    } else {
+        let numerator = (amount as u128)
+            .checked_mul(MAX_FEE_BASIS_POINTS as u128)
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+        let denominator = (MAX_FEE_BASIS_POINTS - basis_points) as u128;
+        // rounds up the amount, since the fee is also rounded up
+        let raw_amount = numerator
+            .checked_add(denominator - 1)
+            .and_then(|n| n.checked_div(denominator))
+            .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+        if raw_amount.saturating_sub(amount as u128) >= maximum_fee as u128 {
+            amount
+                .checked_add(maximum_fee)
+                .ok_or_else(|| CandyGuardError::NumericalOverflowError.into())
+        } else {
+            u64::try_from(raw_amount).map_err(|_| CandyGuardError::NumericalOverflowError.into())
+        }
+    }
+}
+
+/// Returns the current transfer fee (maximum fee, basis points) of a Token-2022 mint
+/// with the `TransferFeeConfig` extension.
+fn get_transfer_fee(mint: &AccountInfo) -> Result<Option<(u64, u64)>> {
+    if !cmp_pubkeys(mint.owner, &spl_token_2022::ID) {
+        return Ok(None);
+    }
+
+    let data = mint.data.borrow();
+    // the mint does not have extensions
+    if data.len() <= ACCOUNT_TYPE_OFFSET {
+        return Ok(None);
+    }
+
+    parse_transfer_fee(&data, Clock::get()?.epoch)
+}
+
+/// Returns the transfer fee (maximum fee, basis points) in effect on the specified
+/// epoch from the extension data of a Token-2022 mint.
+fn parse_transfer_fee(data: &[u8], epoch: u64) -> Result<Option<(u64, u64)>> {
+    // TLV extension data: type (u16), length (u16) and value
+    let mut offset = ACCOUNT_TYPE_OFFSET + 1;
+
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes(*array_ref![data, offset, 2]);
+        let length = u16::from_le_bytes(*array_ref![data, offset + 2, 2]) as usize;
+        offset += 4;
+
+        if extension_type == 0 {
+            // reached the uninitialized part of the data
+            break;
+        } else if extension_type == TRANSFER_FEE_CONFIG_EXTENSION {
+            let value = data
+                .get(offset..offset + length)
+                .ok_or(CandyGuardError::InvalidAccountSize)?;
+            // transfer fee config authority (32) + withdraw withheld authority (32)
+            // + withheld amount (8), followed by the older and newer transfer
+            // fees: epoch (8), maximum fee (8) and basis points (2)
+            if value.len() < 108 {
+                return err!(CandyGuardError::InvalidAccountSize);
+            }
+
+            let newer_epoch = u64::from_le_bytes(*array_ref![value, 90, 8]);
+            let fee_offset = if epoch >= newer_epoch { 90 } else { 72 };
+
+            let maximum_fee = u64::from_le_bytes(*array_ref![value, fee_offset + 8, 8]);
+            let basis_points = u16::from_le_bytes(*array_ref![value, fee_offset + 16, 2]);
+
+            return Ok(Some((maximum_fee, basis_points as u64)));
+        }
+
+        offset += length;
+    }
+
+    Ok(None)
+}
+
 pub fn spl_token_burn(params: TokenBurnParams) -> Result<()> {
     let TokenBurnParams {
         mint,
@@ -110,21 +296,25 @@ pub fn spl_token_burn(params: TokenBurnParams) -> Result<()> {
         authority,
         token_program,
         amount,
+        decimals,
         authority_signer_seeds,
     } = params;
     let mut seeds: Vec<&[&[u8]]> = vec![];
     if let Some(seed) = authority_signer_seeds {
         seeds.push(seed);
     }
+    // builds the instruction directly since the spl-token helpers only
+    // accept the legacy token program
     let result = invoke_signed(
-        &spl_token::instruction::burn(
-            token_program.key,
-            source.key,
-            mint.key,
-            authority.key,
-            &[],
-            amount,
-        )?,
+        &Instruction {
+            program_id: *token_program.key,
+            accounts: vec![
+                AccountMeta::new(*source.key, false),
+                AccountMeta::new(*mint.key, false),
+                AccountMeta::new_readonly(*authority.key, true),
+            ],
+            data: TokenInstruction::BurnChecked { amount, decimals }.pack(),
+        },
         &[source, mint, authority, token_program],
         seeds.as_slice(),
     );
@@ -135,9 +325,11 @@ pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> Result<()> {
     let TokenTransferParams {
         source,
         destination,
+        mint,
         authority,
         token_program,
         amount,
+        decimals,
         authority_signer_seeds,
     } = params;
 
@@ -146,18 +338,170 @@ pub fn spl_token_transfer(params: TokenTransferParams<'_, '_>) -> Result<()> {
         signer_seeds.push(authority_signer_seeds)
     }
 
+    // builds the instruction directly since the spl-token helpers only
+    // accept the legacy token program
     let result = invoke_signed(
-        &spl_token::instruction::transfer(
-            token_program.key,
-            source.key,
-            destination.key,
-            authority.key,
-            &[],
-            amount,
-        )?,
-        &[source, destination, authority, token_program],
+        &Instruction {
+            program_id: *token_program.key,
+            accounts: vec![
+                AccountMeta::new(*source.key, false),
+                AccountMeta::new_readonly(*mint.key, false),
+                AccountMeta::new(*destination.key, false),
+                AccountMeta::new_readonly(*authority.key, true),
+            ],
+            data: TokenInstruction::TransferChecked { amount, decimals }.pack(),
+        },
+        &[source, mint, destination, authority, token_program],
         &signer_seeds,
     );
 
     result.map_err(|_| CandyGuardError::TokenTransferFailed.into())
 }
+
+/// Resizes the account to the specified size, either charging the rent for the additional
+/// space to the payer or withdrawing the rent lamports no longer required to the payer.
+pub fn resize_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+) -> Result<()> {
+    // check whether we need to grow or shrink the account size or not
+    if size != account_info.data_len() {
+        // no risk of overflow here since the sizes will range from DATA_OFFSET to 10_000_000
+        let difference = size as i64 - account_info.data_len() as i64;
+        let snapshot = account_info.lamports();
+
+        if difference > 0 {
+            if difference as usize > MAX_PERMITTED_DATA_INCREASE {
+                return err!(CandyGuardError::DataIncrementLimitExceeded);
+            }
+
+            let lamports_diff = Rent::get()?
+                .minimum_balance(size)
+                .checked_sub(snapshot)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+            msg!("Funding {} lamports for account realloc", lamports_diff);
+
+            invoke(
+                &system_instruction::transfer(payer.key, account_info.key, lamports_diff),
+                &[payer.clone(), account_info.clone(), system_program.clone()],
+            )?;
+        } else {
+            let lamports_diff = snapshot
+                .checked_sub(Rent::get()?.minimum_balance(size))
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+
+            msg!(
+                "Withdrawing {} lamports from account realloc",
+                lamports_diff
+            );
+
+            **account_info.lamports.borrow_mut() = snapshot - lamports_diff;
+
+            **payer.lamports.borrow_mut() = payer
+                .lamports()
+                .checked_add(lamports_diff)
+                .ok_or(CandyGuardError::NumericalOverflowError)?;
+        }
+
+        msg!("Account realloc by {} bytes", difference);
+        // changes the account size to fit the size required by the guards
+        // this means that the size can grow or shrink
+        account_info.realloc(size, false)?;
+    }
+
+    Ok(())
+}
+
+/// Creates a PDA owned by the program, even when the address has already been funded.
+/// Only the lamports missing to be rent exempt are transferred from the payer, then the
+/// space is allocated and the account assigned to the program.
+pub fn create_or_allocate_account<'info>(
+    account_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    size: usize,
+    signer_seeds: &[&[u8]],
+) -> Result<()> {
+    let required_lamports = Rent::get()?
+        .minimum_balance(size)
+        .saturating_sub(account_info.lamports());
+
+    if required_lamports > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account_info.key, required_lamports),
+            &[payer.clone(), account_info.clone(), system_program.clone()],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(account_info.key, size as u64),
+        std::slice::from_ref(account_info),
+        &[signer_seeds],
+    )?;
+
+    invoke_signed(
+        &system_instruction::assign(account_info.key, &crate::ID),
+        std::slice::from_ref(account_info),
+        &[signer_seeds],
+    )?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Token-2022 mint data with a `TransferFeeConfig` extension, where the newer
+    /// transfer fee takes effect on epoch 10.
+    fn transfer_fee_mint(older: (u64, u16), newer: (u64, u16)) -> Vec<u8> {
+        let mut data = vec![0; ACCOUNT_TYPE_OFFSET + 1];
+        data.extend_from_slice(&TRANSFER_FEE_CONFIG_EXTENSION.to_le_bytes());
+        data.extend_from_slice(&108u16.to_le_bytes());
+        data.extend_from_slice(&[0; 72]);
+
+        for (epoch, (maximum_fee, basis_points)) in [(0u64, older), (10u64, newer)] {
+            data.extend_from_slice(&epoch.to_le_bytes());
+            data.extend_from_slice(&maximum_fee.to_le_bytes());
+            data.extend_from_slice(&basis_points.to_le_bytes());
+        }
+
+        data
+    }
+
+    #[test]
+    fn parse_transfer_fee_of_the_epoch() {
+        let data = transfer_fee_mint((1_000, 100), (5_000, 250));
+
+        assert_eq!(parse_transfer_fee(&data, 9).unwrap(), Some((1_000, 100)));
+        assert_eq!(parse_transfer_fee(&data, 10).unwrap(), Some((5_000, 250)));
+
+        // mint without extensions
+        let data = vec![0; ACCOUNT_TYPE_OFFSET + 1];
+        assert_eq!(parse_transfer_fee(&data, 10).unwrap(), None);
+    }
+
+    #[test]
+    fn pre_fee_amount() {
+        // zero fee
+        assert_eq!(calculate_pre_fee_amount(1_000, 5_000, 0).unwrap(), 1_000);
+        assert_eq!(calculate_pre_fee_amount(0, 5_000, 100).unwrap(), 0);
+
+        // 1% fee: 1_000 / 0.99 = 1_010.1, rounded up
+        assert_eq!(calculate_pre_fee_amount(1_000, 5_000, 100).unwrap(), 1_011);
+        // 2.5% fee on an amount that divides exactly
+        assert_eq!(calculate_pre_fee_amount(975, 5_000, 250).unwrap(), 1_000);
+
+        // the fee is capped by the maximum fee
+        assert_eq!(
+            calculate_pre_fee_amount(1_000_000, 5_000, 100).unwrap(),
+            1_005_000
+        );
+        assert_eq!(calculate_pre_fee_amount(1_000, 10, 10_000).unwrap(), 1_010);
+
+        assert!(calculate_pre_fee_amount(u64::MAX, u64::MAX, 10_000).is_err());
+    }
+}